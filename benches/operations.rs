@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
+use prost::Message;
+use substreams_sink_prometheus::{
+    Counter, Gauge, Histogram, PrometheusOperation, PrometheusOperations,
+};
+
+const LABELS: [usize; 3] = [0, 5, 20];
+const OPS: [usize; 3] = [10_000, 100_000, 1_000_000];
+
+fn labels(n: usize) -> HashMap<String, String> {
+    (0..n)
+        .map(|i| (format!("label{i}"), format!("value{i}")))
+        .collect()
+}
+
+fn operations(n: usize) -> Vec<PrometheusOperation> {
+    let mut counter = Counter::from("transfers_total").with(labels(5));
+    let mut gauge = Gauge::from("balance").with(labels(5));
+    (0..n)
+        .map(|i| match i % 2 {
+            0 => counter.inc().into(),
+            _ => gauge.set(i as f64).into(),
+        })
+        .collect()
+}
+
+fn bench_counter_inc(c: &mut Criterion) {
+    let mut group = c.benchmark_group("counter_inc");
+    for n in LABELS {
+        let mut counter = Counter::from("transfers_total").with(labels(n));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{n}_labels")),
+            &n,
+            |b, _| b.iter(|| black_box(counter.inc())),
+        );
+    }
+    group.finish();
+}
+
+fn bench_push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push");
+    for n in OPS {
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, &n| {
+            b.iter_batched(
+                || operations(n),
+                |operations| {
+                    let mut prom_ops: PrometheusOperations = Default::default();
+                    for operation in operations {
+                        prom_ops.push(operation);
+                    }
+                    prom_ops
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_extend(c: &mut Criterion) {
+    let mut group = c.benchmark_group("extend");
+    for n in OPS {
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, &n| {
+            b.iter_batched(
+                || operations(n),
+                |operations| {
+                    let mut prom_ops: PrometheusOperations = Default::default();
+                    prom_ops.extend(operations);
+                    prom_ops
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_encode_decode(c: &mut Criterion) {
+    let mut encode = c.benchmark_group("encode");
+    for n in OPS {
+        let prom_ops = PrometheusOperations {
+            operations: operations(n),
+            ..Default::default()
+        };
+        encode.throughput(Throughput::Bytes(prom_ops.encoded_len() as u64));
+        encode.bench_with_input(BenchmarkId::from_parameter(n), &prom_ops, |b, prom_ops| {
+            b.iter(|| prom_ops.encode_to_vec())
+        });
+    }
+    encode.finish();
+
+    let mut decode = c.benchmark_group("decode");
+    for n in OPS {
+        let bytes = PrometheusOperations {
+            operations: operations(n),
+            ..Default::default()
+        }
+        .encode_to_vec();
+        decode.throughput(Throughput::Bytes(bytes.len() as u64));
+        decode.bench_with_input(BenchmarkId::from_parameter(n), &bytes, |b, bytes| {
+            b.iter(|| PrometheusOperations::decode(bytes.as_slice()).unwrap())
+        });
+    }
+    decode.finish();
+}
+
+// 5,000 gas prices of a block, one operation each or a single `observe_many`
+fn bench_observe(c: &mut Criterion) {
+    let mut group = c.benchmark_group("observe");
+    let values: Vec<f64> = (0..5_000).map(|i| i as f64 / 100.0).collect();
+    let mut histogram = Histogram::from("gas_price_gwei").with(labels(5));
+    group.throughput(Throughput::Elements(values.len() as u64));
+    group.bench_function("single", |b| {
+        b.iter(|| {
+            let mut prom_ops: PrometheusOperations = Default::default();
+            for value in &values {
+                prom_ops.push(histogram.observe(*value));
+            }
+            black_box(prom_ops.encode_to_vec())
+        })
+    });
+    group.bench_function("many", |b| {
+        b.iter(|| {
+            let mut prom_ops: PrometheusOperations = Default::default();
+            prom_ops.push(histogram.observe_many(&values));
+            black_box(prom_ops.encode_to_vec())
+        })
+    });
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_counter_inc, bench_push, bench_extend, bench_encode_decode, bench_observe
+}
+criterion_main!(benches);
@@ -0,0 +1,29 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use prost::Message;
+use substreams_sink_prometheus::{
+    lint, CardinalityLimiter, LabelSanitizer, Overflow, PrometheusOperations, Registry,
+};
+
+// Everything a sink does with the operations of a third-party module:
+// sanitize, limit, lint, apply to the registry and expose. Invalid operations
+// must be reported as errors, never panic.
+fuzz_target!(|data: &[u8]| {
+    let Ok(prom_ops) = PrometheusOperations::decode(data) else {
+        return;
+    };
+    let _ = lint(&prom_ops);
+
+    let sanitizer = LabelSanitizer::new();
+    let mut limiter =
+        CardinalityLimiter::new(16).with_overflow(Overflow::Fold("other".to_string()));
+    let mut registry = Registry::new();
+    for mut operation in prom_ops.operations {
+        operation.labels = sanitizer.sanitize_labels(operation.labels);
+        if let Ok(Some(operation)) = limiter.check(operation) {
+            let _ = registry.apply(&operation);
+        }
+    }
+    let _ = registry.to_text();
+});
@@ -0,0 +1,124 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use prost::Message;
+use substreams_sink_prometheus::{
+    BlockOperations, FinalityBuffer, PrometheusOperations, PushgatewayEncoder, Registry,
+    ReversibleRegistry, SeriesExpiry,
+};
+
+// Operations fed to a run, so the series kept by every component stay bounded
+const MAX_OPERATIONS: usize = 4096;
+
+// A sink following a chain: batches of new blocks, undo signals of forks and final blocks,
+// driving the reorg-aware registry, the finality buffer, the series expiry and the Pushgateway encoder.
+//
+// The input is a sequence of commands, the first byte of each selects it:
+// - batch: 2 bytes of length followed by the encoded `PrometheusOperations` of the next block
+// - undo: 1 byte, number of blocks to roll back
+// - finalize: 1 byte, number of blocks before the head that are final
+// - expire: 1 byte, seconds elapsed
+fuzz_target!(|data: &[u8]| {
+    let mut reversible = ReversibleRegistry::default();
+    let mut buffer = FinalityBuffer::new();
+    let mut expiry = SeriesExpiry::new();
+    let mut encoder = PushgatewayEncoder::new();
+    let mut registry = Registry::new();
+    let mut number = 0u64;
+    let mut timestamp = 1_700_000_000u64;
+    let mut fed = 0usize;
+
+    let mut data = data;
+    while let Some((&command, rest)) = data.split_first() {
+        data = rest;
+        match command % 4 {
+            0 => {
+                let Some((length, rest)) = data.split_first_chunk::<2>() else {
+                    return;
+                };
+                let length = (u16::from_le_bytes(*length) as usize).min(rest.len());
+                let (batch, rest) = rest.split_at(length);
+                data = rest;
+                let Ok(operations) = PrometheusOperations::decode(batch) else {
+                    continue;
+                };
+                fed += operations.operations.len();
+                if fed > MAX_OPERATIONS {
+                    return;
+                }
+                number += 1;
+                timestamp += 12;
+
+                let block = BlockOperations {
+                    number,
+                    id: format!("#{number}"),
+                    operations: operations.clone(),
+                };
+                let before = reversible.registry().clone();
+                if reversible.apply_block(&block).is_err() {
+                    // Applied entirely or not at all
+                    assert_eq!(reversible.registry(), &before);
+                }
+                let before = encoder.clone();
+                if encoder.encode(&operations).is_err() {
+                    assert_eq!(encoder, before);
+                }
+                expiry.track(number, timestamp, &operations);
+                if let Some(operations) = buffer.push(number, operations) {
+                    let _ = registry.apply_all(&operations);
+                }
+            }
+            1 => {
+                let Some((&blocks, rest)) = data.split_first() else {
+                    return;
+                };
+                data = rest;
+                let last_valid_block = number.saturating_sub(blocks as u64);
+                let mut replayed = reversible.registry().clone();
+                let operations = reversible.undo(last_valid_block);
+                buffer.undo(last_valid_block);
+                // The returned operations revert the same changes as the registry
+                for operation in &operations {
+                    replayed.apply(operation).unwrap();
+                }
+                assert_eq!(replayed.to_text(), reversible.registry().to_text());
+                number = last_valid_block;
+            }
+            2 => {
+                let Some((&blocks, rest)) = data.split_first() else {
+                    return;
+                };
+                data = rest;
+                let final_block = number.saturating_sub(blocks as u64);
+                let _rejected = reversible.finalize(final_block);
+                for operations in buffer.finalize(final_block) {
+                    let _ = registry.apply_all(&operations);
+                }
+            }
+            _ => {
+                let Some((&seconds, rest)) = data.split_first() else {
+                    return;
+                };
+                data = rest;
+                timestamp += seconds as u64;
+                for operation in expiry.expire(number, timestamp) {
+                    let _ = registry.apply(&operation);
+                }
+            }
+        }
+
+        // Every operation adds at most one series
+        let series = |registry: &Registry| -> usize {
+            registry
+                .families()
+                .map(|(_, family)| family.series.len())
+                .sum()
+        };
+        assert!(series(reversible.registry()) <= fed);
+        assert!(series(&registry) <= fed);
+        assert!(expiry.len() <= fed);
+        assert!(buffer.len() as u64 <= number);
+    }
+    let _ = reversible.registry().to_text();
+    let _ = registry.to_text();
+});
@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use prost::Message;
+use substreams_sink_prometheus::{PrometheusOperations, PrometheusOperationsWriter};
+
+// Decoding arbitrary bytes must return an error instead of panicking,
+// and whatever decodes must encode back to a valid batch.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(prom_ops) = PrometheusOperations::decode(data) {
+        let encoded = prom_ops.encode_to_vec();
+        assert_eq!(encoded.len(), prom_ops.encoded_len());
+        let decoded = PrometheusOperations::decode(encoded.as_slice()).unwrap();
+        assert_eq!(decoded.operations.len(), prom_ops.operations.len());
+        assert_eq!(decoded.encode_sorted(), prom_ops.encode_sorted());
+    }
+    if let Ok(writer) = PrometheusOperationsWriter::decode(data) {
+        PrometheusOperations::decode(writer.as_bytes()).unwrap();
+    }
+    let _ = PrometheusOperations::decode_stream(data);
+});
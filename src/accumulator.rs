@@ -0,0 +1,209 @@
+use crate::registry::sorted_bounds;
+use crate::{AggregatedObservations, Histogram, SeriesOperation, Summary, DEFAULT_BUCKETS};
+
+/// Collects the observations of a Histogram during a block and emits them as a single
+/// `ObserveAggregated` operation (bucket counts, sum and count) instead of one `observe` per value.
+///
+/// The buckets must be the same as the histogram in the sink, which uses `DEFAULT_BUCKETS` by default.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{Histogram, HistogramAccumulator, PrometheusOperations};
+/// let mut price_impact = HistogramAccumulator::new(Histogram::from("swap_price_impact"));
+/// for value in [0.001, 0.02, 0.3] {
+///     price_impact.observe(value);
+/// }
+/// let mut prom_ops: PrometheusOperations = Default::default();
+/// prom_ops.push(price_impact.finish());
+/// assert_eq!(prom_ops.operations.len(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistogramAccumulator {
+    histogram: Histogram,
+    aggregated: AggregatedObservations,
+}
+
+impl HistogramAccumulator {
+    /// Create new empty HistogramAccumulator using the default histogram buckets
+    #[inline]
+    #[must_use]
+    pub fn new(histogram: Histogram) -> Self {
+        Self::with_buckets(histogram, DEFAULT_BUCKETS.to_vec())
+    }
+
+    /// Create new empty HistogramAccumulator with the given bucket upper bounds, sorted and deduplicated (`NaN` bounds are dropped).
+    ///
+    /// The bounds must be the buckets of the histogram in the sink: `Registry::new()` uses `DEFAULT_BUCKETS`
+    /// and rejects aggregated observations with other bounds, unless it is built with the same `Registry::with_buckets`.
+    #[must_use]
+    pub fn with_buckets(histogram: Histogram, bounds: Vec<f64>) -> Self {
+        let bounds = sorted_bounds(bounds);
+        let bucket_counts = vec![0; bounds.len()];
+        Self {
+            histogram,
+            aggregated: AggregatedObservations {
+                bounds,
+                bucket_counts,
+                sum: 0.0,
+                count: 0,
+            },
+        }
+    }
+
+    /// Adds a single observation
+    pub fn observe(&mut self, value: f64) {
+        let aggregated = &mut self.aggregated;
+        for (bound, count) in aggregated.bounds.iter().zip(&mut aggregated.bucket_counts) {
+            if value <= *bound {
+                *count += 1;
+            }
+        }
+        aggregated.sum += value;
+        aggregated.count += 1;
+    }
+
+    /// Number of observations
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.aggregated.count
+    }
+
+    /// Operation adding all the observations to the Histogram
+    #[inline]
+    #[must_use]
+    pub fn finish(mut self) -> SeriesOperation {
+        self.histogram.observe_aggregated(self.aggregated)
+    }
+}
+
+/// Collects the observations of a Summary during a block and emits their sum and count as a single
+/// `ObserveAggregated` operation instead of one `observe` per value.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{PrometheusOperations, Summary, SummaryAccumulator};
+/// let mut gas_price = SummaryAccumulator::new(Summary::from("gas_price_gwei"));
+/// gas_price.observe(12.5);
+/// gas_price.observe(30.0);
+/// let mut prom_ops: PrometheusOperations = Default::default();
+/// prom_ops.push(gas_price.finish());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SummaryAccumulator {
+    summary: Summary,
+    aggregated: AggregatedObservations,
+}
+
+impl SummaryAccumulator {
+    /// Create new empty SummaryAccumulator
+    #[inline]
+    #[must_use]
+    pub fn new(summary: Summary) -> Self {
+        Self {
+            summary,
+            aggregated: Default::default(),
+        }
+    }
+
+    /// Adds a single observation
+    pub fn observe(&mut self, value: f64) {
+        self.aggregated.sum += value;
+        self.aggregated.count += 1;
+    }
+
+    /// Number of observations
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.aggregated.count
+    }
+
+    /// Operation adding all the observations to the Summary
+    #[inline]
+    #[must_use]
+    pub fn finish(mut self) -> SeriesOperation {
+        self.summary.observe_aggregated(self.aggregated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{labels, PrometheusOperations, Registry};
+
+    #[test]
+    fn test_accumulators() {
+        let values = [0.001, 0.02, 0.3, 0.3, 7.0, 42.0];
+        let histogram = Histogram::from("swap_price_impact").with(labels! {"pool" => "0xa"});
+        let summary = Summary::from("gas_price_gwei");
+        let mut histogram_accumulator = HistogramAccumulator::new(histogram.clone());
+        let mut summary_accumulator = SummaryAccumulator::new(summary.clone());
+        let mut observed = PrometheusOperations::default();
+        for value in values {
+            histogram_accumulator.observe(value);
+            summary_accumulator.observe(value);
+            observed.push(histogram.clone().observe(value));
+            observed.push(summary.clone().observe(value));
+        }
+        assert_eq!(histogram_accumulator.count(), 6);
+        assert_eq!(summary_accumulator.count(), 6);
+
+        let mut aggregated = PrometheusOperations::default();
+        aggregated.push(histogram_accumulator.finish());
+        aggregated.push(summary_accumulator.finish());
+
+        let mut expected = Registry::new();
+        expected.apply_all(&observed).unwrap();
+        let mut registry = Registry::new();
+        registry.apply_all(&aggregated).unwrap();
+        assert_eq!(registry, expected);
+    }
+
+    #[test]
+    fn test_bucket_mismatch() {
+        let histogram = Histogram::from("swap_price_impact");
+        let mut accumulator = HistogramAccumulator::with_buckets(histogram, vec![0.1, 1.0]);
+        accumulator.observe(0.5);
+        let mut prom_ops = PrometheusOperations::default();
+        prom_ops.push(accumulator.finish());
+
+        let mut registry = Registry::new().with_buckets(vec![0.1, 1.0]);
+        registry.apply_all(&prom_ops).unwrap();
+        assert_eq!(
+            registry.to_text(),
+            "# TYPE swap_price_impact histogram\nswap_price_impact_bucket{le=\"0.1\"} 0\nswap_price_impact_bucket{le=\"1\"} 1\nswap_price_impact_bucket{le=\"+Inf\"} 1\nswap_price_impact_sum 0.5\nswap_price_impact_count 1\n"
+        );
+
+        // Buckets the sink histogram doesn't have are not dropped
+        assert_eq!(
+            Registry::new().with_buckets(vec![1.0]).apply_all(&prom_ops),
+            Err(crate::Error::BucketMismatch {
+                name: "swap_price_impact".to_string(),
+                bounds: vec![0.1, 1.0],
+            })
+        );
+        assert_eq!(
+            Registry::new().apply_all(&prom_ops),
+            Err(crate::Error::BucketMismatch {
+                name: "swap_price_impact".to_string(),
+                bounds: vec![0.1, 1.0],
+            })
+        );
+    }
+
+    #[test]
+    fn test_unsorted_buckets() {
+        let mut accumulator = HistogramAccumulator::with_buckets(
+            Histogram::from("swap_price_impact"),
+            vec![1.0, 0.1, f64::NAN, 1.0],
+        );
+        accumulator.observe(0.5);
+        let mut prom_ops = PrometheusOperations::default();
+        prom_ops.push(accumulator.finish());
+
+        let mut registry = Registry::new().with_buckets(vec![0.1, 1.0]);
+        registry.apply_all(&prom_ops).unwrap();
+        assert!(registry
+            .to_text()
+            .contains("swap_price_impact_bucket{le=\"1\"} 1\n"));
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+
+use substreams_sink_prometheus::{Linter, PrometheusOperations, Warning};
+
+use crate::replay::read_input;
+
+pub fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut input = None;
+    let mut units = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--unit" => units.push(args.next().ok_or("--unit requires a unit")?.as_str()),
+            arg if input.is_none() => input = Some(arg),
+            arg => return Err(format!("unexpected argument {arg:?}").into()),
+        }
+    }
+    let bytes = read_input(input.ok_or("missing input file")?)?;
+
+    let warnings = lint(&bytes, &Linter::new().with_units(&units))?;
+    for warning in &warnings {
+        println!("{warning}");
+    }
+    match warnings.len() {
+        0 => Ok(()),
+        count => Err(format!("{count} warning(s)").into()),
+    }
+}
+
+fn lint(bytes: &[u8], linter: &Linter) -> Result<BTreeSet<Warning>, Box<dyn Error>> {
+    let batches = PrometheusOperations::decode_stream(bytes)?;
+    Ok(batches
+        .iter()
+        .flat_map(|batch| linter.lint(batch))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+    use substreams_sink_prometheus::Counter;
+
+    #[test]
+    fn test_lint() {
+        let mut bytes = vec![];
+        for _ in 0..2 {
+            let mut prom_ops: PrometheusOperations = Default::default();
+            prom_ops.push(Counter::from("transfers").inc());
+            prom_ops.encode_length_delimited(&mut bytes).unwrap();
+        }
+
+        let warnings = lint(&bytes, &Linter::new()).unwrap();
+        assert_eq!(warnings.len(), 1);
+    }
+}
@@ -0,0 +1,40 @@
+//! `substreams-prometheus` command line tool.
+//!
+//! Works on recorded module outputs: consecutive length-delimited `PrometheusOperations`
+//! batches, one per block, as written by `PrometheusOperations::encode_length_delimited`.
+use std::error::Error;
+use std::process::ExitCode;
+
+mod lint;
+mod replay;
+
+const USAGE: &str = "Usage: substreams-prometheus <COMMAND>
+
+Commands:
+  replay <FILE> [--output <FILE>] [--buckets <LIST>]
+        Apply the recorded batches of FILE (`-` for stdin) in order and write the
+        resulting /metrics text exposition to stdout or to --output.
+        --buckets sets the histogram bucket bounds, comma separated.
+  lint <FILE> [--unit <UNIT>]...
+        Report metric naming issues found in the recorded batches of FILE (`-` for stdin),
+        exits with an error if any. --unit accepts an additional unit suffix (ex: wei).";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result: Result<(), Box<dyn Error>> = match args.first().map(String::as_str) {
+        Some("replay") => replay::run(&args[1..]),
+        Some("lint") => lint::run(&args[1..]),
+        Some("-h") | Some("--help") | None => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Some(command) => Err(format!("unknown command {command:?}\n\n{USAGE}").into()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
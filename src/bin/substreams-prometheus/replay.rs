@@ -0,0 +1,132 @@
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read, Write};
+
+use substreams_sink_prometheus::{PrometheusOperations, Registry, SeriesExpiry};
+
+pub fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut input = None;
+    let mut output = None;
+    let mut buckets = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => output = Some(args.next().ok_or("--output requires a path")?),
+            "--buckets" => {
+                buckets = Some(parse_buckets(
+                    args.next().ok_or("--buckets requires a list")?,
+                )?)
+            }
+            arg if input.is_none() => input = Some(arg),
+            arg => return Err(format!("unexpected argument {arg:?}").into()),
+        }
+    }
+    let bytes = read_input(input.ok_or("missing input file")?)?;
+    let mut registry = Registry::new();
+    if let Some(buckets) = buckets {
+        registry = registry.with_buckets(buckets);
+    }
+    let text = replay(&bytes, registry)?;
+
+    match output {
+        Some(path) => fs::write(path, text)?,
+        None => io::stdout().write_all(text.as_bytes())?,
+    }
+    Ok(())
+}
+
+/// Read the whole input file, `-` reads stdin
+pub fn read_input(path: &str) -> io::Result<Vec<u8>> {
+    if path == "-" {
+        let mut bytes = vec![];
+        io::stdin().read_to_end(&mut bytes)?;
+        Ok(bytes)
+    } else {
+        fs::read(path)
+    }
+}
+
+// Batches are one per block without their block number or timestamp, so `ttl_blocks` counts batches
+// and `ttl_seconds` never expires.
+fn replay(bytes: &[u8], mut registry: Registry) -> Result<String, Box<dyn Error>> {
+    let batches = PrometheusOperations::decode_stream(bytes)?;
+    let mut expiry = SeriesExpiry::new();
+    for (index, batch) in batches.iter().enumerate() {
+        registry
+            .apply_all(batch)
+            .map_err(|err| format!("batch #{index}: {err}"))?;
+        expiry.track(index as u64, 0, batch);
+        for operation in expiry.expire(index as u64, 0) {
+            registry
+                .apply(&operation)
+                .map_err(|err| format!("batch #{index}: {err}"))?;
+        }
+    }
+    Ok(registry.to_text())
+}
+
+fn parse_buckets(list: &str) -> Result<Vec<f64>, Box<dyn Error>> {
+    let mut buckets = list
+        .split(',')
+        .map(|bound| match bound.trim().parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(value),
+            Ok(_) => Err(format!("invalid bucket {bound:?}: not a finite number")),
+            Err(err) => Err(format!("invalid bucket {bound:?}: {err}")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    buckets.sort_by(f64::total_cmp);
+    buckets.dedup();
+    Ok(buckets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+    use substreams_sink_prometheus::{labels, Counter, Gauge};
+
+    #[test]
+    fn test_replay() {
+        let mut counter = Counter::from("transfers_total");
+        let mut gauge = Gauge::from("head");
+        let mut bytes = vec![];
+        for block in 1..=3 {
+            let mut prom_ops: PrometheusOperations = Default::default();
+            prom_ops.push(counter.inc());
+            prom_ops.push(gauge.set(block as f64));
+            prom_ops.encode_length_delimited(&mut bytes).unwrap();
+        }
+
+        assert_eq!(
+            replay(&bytes, Registry::new()).unwrap(),
+            "# TYPE head gauge\nhead 3\n# TYPE transfers_total counter\ntransfers_total 3\n"
+        );
+        assert_eq!(parse_buckets("1, 0.5").unwrap(), vec![0.5, 1.0]);
+        assert_eq!(parse_buckets("1,0.5,1").unwrap(), vec![0.5, 1.0]);
+        assert!(parse_buckets("1,x").is_err());
+        assert!(parse_buckets("1,NaN").is_err());
+        assert!(parse_buckets("1,inf").is_err());
+        assert!(parse_buckets("-inf,1").is_err());
+    }
+
+    #[test]
+    fn test_replay_ttl() {
+        let pool = |pool: &str| Gauge::from("pool_liquidity").with(labels! {"pool" => pool});
+        let mut bytes = vec![];
+        let mut prom_ops = PrometheusOperations::default().with_ttl_blocks("pool_liquidity", 2);
+        prom_ops.push(pool("0xa").set(1.0));
+        prom_ops.push(pool("0xb").set(2.0));
+        prom_ops.encode_length_delimited(&mut bytes).unwrap();
+        for _ in 0..2 {
+            let mut prom_ops = PrometheusOperations::default();
+            prom_ops.push(pool("0xb").set(3.0));
+            prom_ops.encode_length_delimited(&mut bytes).unwrap();
+        }
+
+        // 0xa gets no update for 2 batches
+        assert_eq!(
+            replay(&bytes, Registry::new()).unwrap(),
+            "# TYPE pool_liquidity gauge\npool_liquidity{pool=\"0xb\"} 3\n"
+        );
+    }
+}
@@ -0,0 +1,383 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::{
+    counter_op, gauge_op, histogram_op, prometheus_operation, registry::to_labels, summary_op,
+    Labels, PrometheusOperation, PrometheusOperations,
+};
+
+/// Label value used by `Overflow::Fold`
+pub const OTHER: &str = "other";
+
+/// What to do with a new series once a metric reached its limit.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Drop the operation
+    #[default]
+    Drop,
+    /// Replace every label value with the given value, so the new series end up in a single folded series per metric.
+    /// Operations with other label names than the first folded one are dropped, the metric never exceeds its limit plus one.
+    ///
+    /// Operations setting an absolute value (gauge `set`, counter `set_total`) are dropped instead:
+    /// the folded series would hold the last value of an arbitrary series.
+    Fold(String),
+    /// Return a `CardinalityError`
+    Error,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CardinalityError {
+    pub name: String,
+    pub limit: usize,
+}
+
+impl fmt::Display for CardinalityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "metric {:?}: more than {} series", self.name, self.limit)
+    }
+}
+
+impl std::error::Error for CardinalityError {}
+
+/// Caps the number of distinct label sets per metric name before operations leave the module.
+///
+/// `Remove` and `Reset` operations always go through and free the series they delete.
+///
+/// The series seen so far are kept in memory. Substreams handlers don't keep memory between blocks,
+/// so a limiter created in a handler caps the series of a single block; to cap them over the whole stream,
+/// restore the series already emitted with `with_series` (ex: from a store keyed by metric name and labels).
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{labels, CardinalityLimiter, Counter, Overflow, PrometheusOperations, OTHER};
+/// let mut prom_ops: PrometheusOperations = Default::default();
+/// let mut limiter = CardinalityLimiter::new(1).with_overflow(Overflow::Fold(OTHER.to_string()));
+///
+/// for pool in ["0xa", "0xb", "0xc"] {
+///     limiter.push(&mut prom_ops, Counter::from("swaps_total").with(labels! {"pool" => pool}).inc()).unwrap();
+/// }
+/// // The swaps of the pools over the limit are counted in the "other" series
+/// assert_eq!(prom_ops.operations[0].labels, labels! {"pool" => "0xa"});
+/// assert_eq!(prom_ops.operations[1].labels, labels! {"pool" => "other"});
+/// assert_eq!(prom_ops.operations[2].labels, labels! {"pool" => "other"});
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CardinalityLimiter {
+    limit: usize,
+    limits: HashMap<String, usize>,
+    overflow: Overflow,
+    series: HashMap<String, HashSet<Labels>>,
+    folded: HashMap<String, Labels>,
+}
+
+impl CardinalityLimiter {
+    /// Create new CardinalityLimiter allowing `limit` series per metric, dropping the others
+    #[inline]
+    #[must_use]
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            limits: Default::default(),
+            overflow: Default::default(),
+            series: Default::default(),
+            folded: Default::default(),
+        }
+    }
+
+    /// Set the behavior once a metric reached its limit
+    #[inline]
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Override the limit of a single metric
+    #[inline]
+    pub fn with_limit(mut self, name: &str, limit: usize) -> Self {
+        self.limits.insert(name.to_string(), limit);
+        self
+    }
+
+    /// Count the series of the metric already emitted, as restored from a store.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_prometheus::{labels, CardinalityLimiter, Gauge};
+    /// let mut limiter = CardinalityLimiter::new(1).with_series("balance", [labels! {"holder" => "0xa"}]);
+    /// let operation = Gauge::from("balance").with(labels! {"holder" => "0xb"}).set(1.0);
+    /// assert_eq!(limiter.check(operation).unwrap(), None);
+    /// ```
+    pub fn with_series<I>(mut self, name: &str, series: I) -> Self
+    where
+        I: IntoIterator<Item = HashMap<String, String>>,
+    {
+        let known = self.series.entry(name.to_string()).or_default();
+        known.extend(series.into_iter().map(|labels| to_labels(&labels)));
+        self
+    }
+
+    /// Number of distinct series currently tracked for the metric, not counting its folded series
+    pub fn len(&self, name: &str) -> usize {
+        self.series.get(name).map(HashSet::len).unwrap_or_default()
+    }
+
+    /// Check the operation against the limit of its metric.
+    ///
+    /// Returns the operation unchanged while the metric is under its limit, or as decided by the `Overflow` otherwise:
+    /// `None` when dropped, the operation with folded labels, or an error.
+    pub fn check(
+        &mut self,
+        operation: impl Into<PrometheusOperation>,
+    ) -> Result<Option<PrometheusOperation>, CardinalityError> {
+        let mut operation = operation.into();
+        let limit = *self.limits.get(&operation.name).unwrap_or(&self.limit);
+        let labels = to_labels(&operation.labels);
+
+        // Unknown metrics have nothing to free, and aren't tracked until a series is created
+        if is_reset(&operation) {
+            self.series.remove(&operation.name);
+            self.folded.remove(&operation.name);
+            return Ok(Some(operation));
+        }
+        if is_remove(&operation) {
+            if let Some(series) = self.series.get_mut(&operation.name) {
+                series.remove(&labels);
+            }
+            if self.folded.get(&operation.name) == Some(&labels) {
+                self.folded.remove(&operation.name);
+            }
+            return Ok(Some(operation));
+        }
+        let series = self.series.entry(operation.name.to_owned()).or_default();
+        if series.contains(&labels) || series.len() < limit {
+            series.insert(labels);
+            return Ok(Some(operation));
+        }
+        match &self.overflow {
+            Overflow::Drop => Ok(None),
+            Overflow::Fold(_) if is_absolute(&operation) => Ok(None),
+            Overflow::Fold(other) => {
+                for value in operation.labels.values_mut() {
+                    *value = other.to_owned();
+                }
+                let labels = to_labels(&operation.labels);
+                if series.contains(&labels) {
+                    return Ok(Some(operation));
+                }
+                let folded = self
+                    .folded
+                    .entry(operation.name.to_owned())
+                    .or_insert_with(|| labels.clone());
+                Ok((*folded == labels).then_some(operation))
+            }
+            Overflow::Error => Err(CardinalityError {
+                name: operation.name,
+                limit,
+            }),
+        }
+    }
+
+    /// Check the operation and push it to `operations` unless it is dropped
+    pub fn push(
+        &mut self,
+        operations: &mut PrometheusOperations,
+        operation: impl Into<PrometheusOperation>,
+    ) -> Result<(), CardinalityError> {
+        if let Some(operation) = self.check(operation)? {
+            operations.push(operation);
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn is_remove(operation: &PrometheusOperation) -> bool {
+    match &operation.operation {
+        Some(prometheus_operation::Operation::Counter(op)) => {
+            op.operation == counter_op::Operation::Remove as i32
+        }
+        Some(prometheus_operation::Operation::Gauge(op)) => {
+            op.operation == gauge_op::Operation::Remove as i32
+        }
+        Some(prometheus_operation::Operation::Histogram(op)) => {
+            op.operation == histogram_op::Operation::Remove as i32
+        }
+        Some(prometheus_operation::Operation::Summary(op)) => {
+            op.operation == summary_op::Operation::Remove as i32
+        }
+        None => false,
+    }
+}
+
+// Operations setting the series to a value instead of adding to it
+fn is_absolute(operation: &PrometheusOperation) -> bool {
+    match &operation.operation {
+        Some(prometheus_operation::Operation::Counter(op)) => {
+            op.operation == counter_op::Operation::SetTotal as i32
+        }
+        Some(prometheus_operation::Operation::Gauge(op)) => {
+            op.operation == gauge_op::Operation::Set as i32
+                || op.operation == gauge_op::Operation::SetToCurrentTime as i32
+        }
+        _ => false,
+    }
+}
+
+pub(crate) fn is_reset(operation: &PrometheusOperation) -> bool {
+    match &operation.operation {
+        Some(prometheus_operation::Operation::Counter(op)) => {
+            op.operation == counter_op::Operation::Reset as i32
+        }
+        Some(prometheus_operation::Operation::Gauge(op)) => {
+            op.operation == gauge_op::Operation::Reset as i32
+        }
+        Some(prometheus_operation::Operation::Histogram(op)) => {
+            op.operation == histogram_op::Operation::Reset as i32
+        }
+        Some(prometheus_operation::Operation::Summary(op)) => {
+            op.operation == summary_op::Operation::Reset as i32
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{labels, Counter, Gauge};
+
+    fn holder(address: &str) -> HashMap<String, String> {
+        HashMap::from([("holder".to_string(), address.to_string())])
+    }
+
+    #[test]
+    fn test_cardinality_limiter() {
+        let mut gauge = Gauge::from("balance");
+        let mut limiter = CardinalityLimiter::new(2);
+
+        assert!(limiter
+            .check(gauge.clone().with(holder("0xa")).set(1.0))
+            .unwrap()
+            .is_some());
+        assert!(limiter
+            .check(gauge.clone().with(holder("0xb")).set(1.0))
+            .unwrap()
+            .is_some());
+        assert!(limiter
+            .check(gauge.clone().with(holder("0xa")).set(2.0))
+            .unwrap()
+            .is_some());
+        assert_eq!(
+            limiter.check(gauge.clone().with(holder("0xc")).set(1.0)),
+            Ok(None)
+        );
+        assert_eq!(limiter.len("balance"), 2);
+
+        assert!(limiter
+            .check(gauge.remove(holder("0xa")))
+            .unwrap()
+            .is_some());
+        assert!(limiter
+            .check(gauge.clone().with(holder("0xc")).set(1.0))
+            .unwrap()
+            .is_some());
+
+        // Removing or resetting a metric never seen doesn't track it
+        let mut limiter = CardinalityLimiter::new(2);
+        let mut pools = Gauge::from("pools");
+        assert!(limiter
+            .check(pools.remove(holder("0xa")))
+            .unwrap()
+            .is_some());
+        assert!(limiter.check(pools.reset()).unwrap().is_some());
+        assert_eq!(limiter, CardinalityLimiter::new(2));
+    }
+
+    #[test]
+    fn test_with_series() {
+        let mut limiter = CardinalityLimiter::new(2).with_series("balance", [holder("0xa")]);
+        assert_eq!(limiter.len("balance"), 1);
+
+        let mut balance = Gauge::from("balance").with(holder("0xa"));
+        assert!(limiter.check(balance.set(1.0)).unwrap().is_some());
+        let mut balance = Gauge::from("balance").with(holder("0xb"));
+        assert!(limiter.check(balance.set(1.0)).unwrap().is_some());
+        let mut balance = Gauge::from("balance").with(holder("0xc"));
+        assert_eq!(limiter.check(balance.set(1.0)).unwrap(), None);
+    }
+
+    #[test]
+    fn test_cardinality_overflow() {
+        let gauge = Gauge::from("balance");
+        let mut limiter =
+            CardinalityLimiter::new(1).with_overflow(Overflow::Fold(OTHER.to_string()));
+        limiter
+            .check(gauge.clone().with(holder("0xa")).set(1.0))
+            .unwrap();
+        let folded = limiter
+            .check(gauge.clone().with(holder("0xb")).add(1.0))
+            .unwrap()
+            .unwrap();
+        assert_eq!(folded.labels, holder(OTHER));
+
+        // A folded series would hold the value of any of the series
+        assert_eq!(
+            limiter.check(gauge.clone().with(holder("0xc")).set(1.0)),
+            Ok(None)
+        );
+        let mut volume = Counter::from("volume_total").with(holder("0xa"));
+        limiter.check(volume.set_total(1.0)).unwrap();
+        let mut volume = Counter::from("volume_total").with(holder("0xb"));
+        assert_eq!(limiter.check(volume.set_total(2.0)), Ok(None));
+
+        // Every label is folded, even the ones with values already seen
+        let transfers = |token: &str, holder: &str| {
+            Counter::from("transfers_total").with(labels! {"token" => token, "holder" => holder})
+        };
+        let mut limiter =
+            CardinalityLimiter::new(2).with_overflow(Overflow::Fold(OTHER.to_string()));
+        limiter.check(transfers("USDT", "0xa").inc()).unwrap();
+        limiter.check(transfers("DAI", "0xb").inc()).unwrap();
+        let folded = limiter
+            .check(transfers("USDT", "0xc").inc())
+            .unwrap()
+            .unwrap();
+        assert_eq!(folded.labels, labels! {"token" => OTHER, "holder" => OTHER});
+
+        // Other label names can't be folded in the same series
+        let mut swaps = Counter::from("transfers_total").with(labels! {"pool" => "0xa"});
+        assert_eq!(limiter.check(swaps.inc()), Ok(None));
+
+        let mut limiter = CardinalityLimiter::new(1)
+            .with_overflow(Overflow::Error)
+            .with_limit("balance", 0);
+        assert_eq!(
+            limiter.check(gauge.with(holder("0xa")).set(1.0)),
+            Err(CardinalityError {
+                name: "balance".to_string(),
+                limit: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_cardinality_fold_bound() {
+        let limit = 3;
+        let mut limiter =
+            CardinalityLimiter::new(limit).with_overflow(Overflow::Fold(OTHER.to_string()));
+        let values = ["a", "b", "c", "d"];
+        let mut emitted = HashSet::new();
+        for token in values {
+            for holder in values {
+                for pool in values {
+                    let labels = labels! {"token" => token, "holder" => holder, "pool" => pool};
+                    let mut transfers = Counter::from("transfers_total").with(labels);
+                    if let Some(operation) = limiter.check(transfers.inc()).unwrap() {
+                        emitted.insert(to_labels(&operation.labels));
+                    }
+                }
+            }
+        }
+        assert_eq!(emitted.len(), limit + 1);
+        assert_eq!(limiter.len("transfers_total"), limit);
+    }
+}
@@ -1,11 +1,13 @@
 use std::collections::HashMap;
 
-use crate::{PrometheusOperation, prometheus_operation, CounterOp, counter_op};
+use crate::{prometheus_operation, Series, SeriesOperation, CounterOp, counter_op};
 
-#[derive(Eq, Debug, PartialEq, Default)]
+/// Builds the operations of a Counter series.
+///
+/// The methods return a `SeriesOperation` sharing the name and labels interned by `from` and `with`, see `Series`.
+#[derive(Clone, Eq, Debug, PartialEq, Default)]
 pub struct Counter {
-    pub name: String,
-    pub labels: HashMap<String, String>,
+    series: Series,
 }
 
 impl Counter {
@@ -20,8 +22,7 @@ impl Counter {
     #[must_use]
     pub fn from(name: &str) -> Self {
         Self {
-            name: name.to_string(),
-            labels: Default::default(),
+            series: Series::from_parts(name.to_string(), Default::default()),
         }
     }
 
@@ -38,10 +39,22 @@ impl Counter {
     /// ```
     #[inline]
     pub fn with(mut self, labels: HashMap<String, String>) -> Self {
-        self.labels = labels;
+        self.series = self.series.with_labels(labels);
         self
     }
 
+    /// Metric name of the Counter
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.series.name()
+    }
+
+    /// Labels set by `with`
+    #[inline]
+    pub fn labels(&self) -> &HashMap<String, String> {
+        self.series.labels()
+    }
+
     /// Increments the Counter by 1.
     ///
     /// ### Example
@@ -52,16 +65,8 @@ impl Counter {
     /// ```
     #[inline]
     #[must_use]
-    pub fn inc(&mut self) -> PrometheusOperation {
-        let op = CounterOp {
-            value: 1.0,
-            operation: counter_op::Operation::Inc.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: self.labels.to_owned(),
-            operation: Some(prometheus_operation::Operation::Counter(op)),
-        }
+    pub fn inc(&mut self) -> SeriesOperation {
+        self.operation(CounterOp::inc())
     }
 
     /// Adds an arbitrary value to a Counter. (Returns an error if the value is < 0.)
@@ -74,15 +79,43 @@ impl Counter {
     /// ```
     #[inline]
     #[must_use]
-    pub fn add(&mut self, value: f64) -> PrometheusOperation {
-        let op = CounterOp {
-            value,
-            operation: counter_op::Operation::Add.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: self.labels.to_owned(),
-            operation: Some(prometheus_operation::Operation::Counter(op)),
+    pub fn add(&mut self, value: f64) -> SeriesOperation {
+        self.operation(CounterOp::add(value))
+    }
+
+    /// Sets the Counter to its cumulative total, which can only move forward. (Returns an error if the value is lower.)
+    ///
+    /// Totals kept by the module (ex: in a store) survive restarts of the sink, unlike the sum of `add` operations.
+    /// Remove the series first to reset it on purpose.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_prometheus::{PrometheusOperations, Counter};
+    /// let mut prom_ops: PrometheusOperations = Default::default();
+    /// prom_ops.push(Counter::from("counter_name").set_total(1_000_000.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn set_total(&mut self, value: f64) -> SeriesOperation {
+        self.operation(CounterOp::set_total(value))
+    }
+
+    /// Initialize the metrics for the given combination of labels to zero
+    ///
+    /// ### Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use substreams_sink_prometheus::{PrometheusOperations, Counter};
+    /// let mut prom_ops: PrometheusOperations = Default::default();
+    /// let labels = HashMap::from([("label1".to_string(), "value1".to_string())]);
+    /// prom_ops.push(Counter::from("counter_name").zero(labels));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn zero(&mut self, labels: HashMap<String, String>) -> SeriesOperation {
+        SeriesOperation {
+            series: Series::from_parts(self.name().to_owned(), labels),
+            operation: Some(prometheus_operation::Operation::Counter(CounterOp::zero())),
         }
     }
 
@@ -98,15 +131,10 @@ impl Counter {
     /// ```
     #[inline]
     #[must_use]
-    pub fn remove(&mut self, labels: HashMap<String, String>) -> PrometheusOperation {
-        let op = CounterOp {
-            value: f64::NAN,
-            operation: counter_op::Operation::Remove.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels,
-            operation: Some(prometheus_operation::Operation::Counter(op)),
+    pub fn remove(&mut self, labels: HashMap<String, String>) -> SeriesOperation {
+        SeriesOperation {
+            series: Series::from_parts(self.name().to_owned(), labels),
+            operation: Some(prometheus_operation::Operation::Counter(CounterOp::remove())),
         }
     }
 
@@ -120,15 +148,80 @@ impl Counter {
     /// ```
     #[inline]
     #[must_use]
-    pub fn reset(&mut self) -> PrometheusOperation {
-        let op = CounterOp {
+    pub fn reset(&mut self) -> SeriesOperation {
+        SeriesOperation {
+            series: Series::from_parts(self.name().to_owned(), Default::default()),
+            operation: Some(prometheus_operation::Operation::Counter(CounterOp::reset())),
+        }
+    }
+
+    #[inline]
+    fn operation(&self, op: CounterOp) -> SeriesOperation {
+        SeriesOperation {
+            series: self.series.clone(),
+            operation: Some(prometheus_operation::Operation::Counter(op)),
+        }
+    }
+}
+
+impl CounterOp {
+    /// Increments the Counter by 1.
+    #[inline]
+    #[must_use]
+    pub fn inc() -> Self {
+        Self {
+            value: 1.0,
+            operation: counter_op::Operation::Inc.into(),
+        }
+    }
+
+    /// Adds an arbitrary value to a Counter. (Returns an error if the value is < 0.)
+    #[inline]
+    #[must_use]
+    pub fn add(value: f64) -> Self {
+        Self {
+            value,
+            operation: counter_op::Operation::Add.into(),
+        }
+    }
+
+    /// Sets the Counter to its cumulative total, which can only move forward. (Returns an error if the value is lower.)
+    #[inline]
+    #[must_use]
+    pub fn set_total(value: f64) -> Self {
+        Self {
+            value,
+            operation: counter_op::Operation::SetTotal.into(),
+        }
+    }
+
+    /// Initialize the metrics for the given combination of labels to zero
+    #[inline]
+    #[must_use]
+    pub fn zero() -> Self {
+        Self {
+            value: f64::NAN,
+            operation: counter_op::Operation::Zero.into(),
+        }
+    }
+
+    /// Remove metrics for the given label values
+    #[inline]
+    #[must_use]
+    pub fn remove() -> Self {
+        Self {
+            value: f64::NAN,
+            operation: counter_op::Operation::Remove.into(),
+        }
+    }
+
+    /// Reset counter values
+    #[inline]
+    #[must_use]
+    pub fn reset() -> Self {
+        Self {
             value: f64::NAN,
             operation: counter_op::Operation::Reset.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: Default::default(),
-            operation: Some(prometheus_operation::Operation::Counter(op)),
         }
     }
 }
@@ -136,7 +229,10 @@ impl Counter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::series::tests::{arb_labels, arb_name, check_operations};
     use crate::PrometheusOperations;
+    use prost::Message;
+    use proptest::prelude::*;
     use std::collections::HashMap;
 
     #[test]
@@ -151,4 +247,41 @@ mod tests {
         prom_ops.push(counter.remove(labels));
         assert_eq!(prom_ops.operations.len(), 4);
     }
+
+    #[test]
+    fn test_counter_encode() {
+        let labels = HashMap::from([("label1".to_string(), "value1".to_string())]);
+        let mut counter = Counter::from("a").with(labels);
+
+        let mut buf = vec![];
+        counter.inc().encode(&mut buf);
+        counter.add(123.456).encode(&mut buf);
+        let prom_ops = PrometheusOperations::decode(buf.as_slice()).unwrap();
+        assert_eq!(prom_ops.operations, vec![counter.inc(), counter.add(123.456)]);
+
+        // Labels set after an encode are encoded too
+        let labels = HashMap::from([("label1".to_string(), "value2".to_string())]);
+        let mut counter = counter.with(labels);
+        let mut buf = vec![];
+        counter.inc().encode(&mut buf);
+        let prom_ops = PrometheusOperations::decode(buf.as_slice()).unwrap();
+        assert_eq!(prom_ops.operations, vec![counter.inc()]);
+        assert_eq!(counter.labels()["label1"], "value2");
+    }
+
+    proptest! {
+        // Every builder method emits its own operation, never `OPERATION_UNSPECIFIED`, and survives a round trip.
+        #[test]
+        fn test_counter_operations(name in arb_name(), labels in arb_labels(), value in any::<f64>()) {
+            let mut counter = Counter::from(&name).with(labels.clone());
+            check_operations(&name, &labels, [
+                (counter.inc(), counter_op::Operation::Inc as i32, Some(1.0)),
+                (counter.add(value), counter_op::Operation::Add as i32, Some(value)),
+                (counter.set_total(value), counter_op::Operation::SetTotal as i32, Some(value)),
+                (counter.zero(labels.clone()), counter_op::Operation::Zero as i32, None),
+                (counter.remove(labels.clone()), counter_op::Operation::Remove as i32, None),
+                (counter.reset(), counter_op::Operation::Reset as i32, None),
+            ])?;
+        }
+    }
 }
@@ -0,0 +1,237 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::cardinality::{is_remove, is_reset};
+use crate::registry::to_labels;
+use crate::reorg::remove;
+use crate::{prometheus_operation, Labels, MetricType, PrometheusOperation, PrometheusOperations};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct LastUpdate {
+    kind: MetricType,
+    block_number: u64,
+    timestamp: u64,
+}
+
+/// Removes the series of metrics with a TTL (`ttl_blocks` or `ttl_seconds` of a batch)
+/// once they receive no update for that long, without emitting `remove` operations for them in every block.
+///
+/// A TTL applies to the series updated in the batch declaring it and in the later batches,
+/// it stays until another batch changes it (0 disables it).
+/// `expire` returns the `remove` operations of the expired series, to apply like any other operation.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{labels, Gauge, PrometheusOperations, SeriesExpiry};
+/// let mut expiry = SeriesExpiry::new();
+/// let mut liquidity = Gauge::from("pool_liquidity").with(labels! {"pool" => "0xa"});
+/// let mut prom_ops = PrometheusOperations::default().with_ttl_blocks("pool_liquidity", 100);
+/// prom_ops.push(liquidity.set(1000.0));
+/// expiry.track(1, 1_700_000_000, &prom_ops);
+///
+/// assert!(expiry.expire(100, 1_700_001_188).is_empty());
+/// let removed = expiry.expire(101, 1_700_001_200);
+/// assert_eq!(removed.len(), 1);
+/// assert_eq!(removed[0].labels, labels! {"pool" => "0xa"});
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct SeriesExpiry {
+    ttl_blocks: HashMap<String, u64>,
+    ttl_seconds: HashMap<String, u64>,
+    series: BTreeMap<(String, Labels), LastUpdate>,
+}
+
+impl SeriesExpiry {
+    /// Create new SeriesExpiry without any TTL
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Record the TTLs of the batch and the last update of its series, for block `block_number`
+    /// at `timestamp` (Unix time in seconds).
+    ///
+    /// Only the series of metrics with a TTL are tracked.
+    pub fn track(&mut self, block_number: u64, timestamp: u64, operations: &PrometheusOperations) {
+        for (name, ttl) in &operations.ttl_blocks {
+            set_ttl(&mut self.ttl_blocks, name, *ttl);
+        }
+        for (name, ttl) in &operations.ttl_seconds {
+            set_ttl(&mut self.ttl_seconds, name, *ttl);
+        }
+        self.series.retain(|(name, _), _| {
+            self.ttl_blocks.contains_key(name) || self.ttl_seconds.contains_key(name)
+        });
+
+        for operation in &operations.operations {
+            let name = &operation.name;
+            if !self.ttl_blocks.contains_key(name) && !self.ttl_seconds.contains_key(name) {
+                continue;
+            }
+            let kind = match &operation.operation {
+                Some(prometheus_operation::Operation::Counter(_)) => MetricType::Counter,
+                Some(prometheus_operation::Operation::Gauge(_)) => MetricType::Gauge,
+                Some(prometheus_operation::Operation::Histogram(_)) => MetricType::Histogram,
+                Some(prometheus_operation::Operation::Summary(_)) => MetricType::Summary,
+                None => continue,
+            };
+            let key = (name.to_owned(), to_labels(&operation.labels));
+            if is_reset(operation) {
+                self.series.retain(|(series, _), _| series != name);
+            } else if is_remove(operation) {
+                self.series.remove(&key);
+            } else {
+                let last_update = LastUpdate {
+                    kind,
+                    block_number,
+                    timestamp,
+                };
+                self.series.insert(key, last_update);
+            }
+        }
+
+        // The other series of snapshot metrics were removed by the batch
+        for name in &operations.snapshot {
+            let labels: HashSet<Labels> = operations
+                .operations
+                .iter()
+                .filter(|operation| &operation.name == name)
+                .map(|operation| to_labels(&operation.labels))
+                .collect();
+            self.series.retain(|(series, series_labels), _| {
+                series != name || labels.contains(series_labels)
+            });
+        }
+    }
+
+    /// Stop tracking the series without update for their TTL at block `block_number` and `timestamp`,
+    /// and return the operations removing them ordered by metric name and labels.
+    pub fn expire(&mut self, block_number: u64, timestamp: u64) -> Vec<PrometheusOperation> {
+        let mut operations = vec![];
+        self.series.retain(|(name, labels), last_update| {
+            let expired_blocks = self.ttl_blocks.get(name).map_or(false, |ttl| {
+                block_number.saturating_sub(last_update.block_number) >= *ttl
+            });
+            let expired_seconds = self.ttl_seconds.get(name).map_or(false, |ttl| {
+                timestamp.saturating_sub(last_update.timestamp) >= *ttl
+            });
+            if expired_blocks || expired_seconds {
+                operations.push(remove(last_update.kind, name, labels));
+            }
+            !expired_blocks && !expired_seconds
+        });
+        operations
+    }
+
+    /// Number of tracked series
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.series.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.series.is_empty()
+    }
+}
+
+fn set_ttl(ttls: &mut HashMap<String, u64>, name: &str, ttl: u64) {
+    match ttl {
+        0 => ttls.remove(name),
+        _ => ttls.insert(name.to_owned(), ttl),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{labels, Counter, Gauge, Registry};
+
+    // Remove operations have a NaN value, compare their encoding
+    fn encoded(operations: Vec<PrometheusOperation>) -> Vec<u8> {
+        PrometheusOperations {
+            operations,
+            ..Default::default()
+        }
+        .encode_sorted()
+    }
+
+    #[test]
+    fn test_series_expiry() {
+        let pool = |pool: &str| Gauge::from("pool_liquidity").with(labels! {"pool" => pool});
+        let mut swaps = Counter::from("swaps_total").with(labels! {"pool" => "0xa"});
+        let mut registry = Registry::new();
+        let mut expiry = SeriesExpiry::new();
+
+        let mut block1 = PrometheusOperations::default()
+            .with_ttl_blocks("pool_liquidity", 10)
+            .with_ttl_seconds("swaps_total", 60);
+        block1.push(pool("0xa").set(1.0));
+        block1.push(pool("0xb").set(2.0));
+        block1.push(swaps.inc());
+        registry.apply_all(&block1).unwrap();
+        expiry.track(1, 1000, &block1);
+        assert_eq!(expiry.len(), 3);
+
+        let mut block2 = PrometheusOperations::default();
+        block2.push(pool("0xb").set(3.0));
+        registry.apply_all(&block2).unwrap();
+        expiry.track(5, 1048, &block2);
+
+        // 0xa expires after 10 blocks, swaps_total after 60 seconds
+        let block11 = PrometheusOperations {
+            operations: expiry.expire(11, 1059),
+            ..Default::default()
+        };
+        assert_eq!(
+            block11.encode_sorted(),
+            encoded(vec![pool("0xa").remove(labels! {"pool" => "0xa"}).into()])
+        );
+        assert_eq!(
+            encoded(expiry.expire(12, 1060)),
+            encoded(vec![swaps.remove(labels! {"pool" => "0xa"}).into()])
+        );
+        registry.apply_all(&block11).unwrap();
+        assert_eq!(
+            registry.to_text(),
+            "# TYPE pool_liquidity gauge\npool_liquidity{pool=\"0xb\"} 3\n# TYPE swaps_total counter\nswaps_total{pool=\"0xa\"} 1\n"
+        );
+
+        // Disabling the TTL stops tracking the series
+        expiry.track(
+            13,
+            1070,
+            &PrometheusOperations::default().with_ttl_blocks("pool_liquidity", 0),
+        );
+        assert!(expiry.is_empty());
+        assert!(expiry.expire(1000, 9999).is_empty());
+    }
+
+    #[test]
+    fn test_series_expiry_removed() {
+        let pool = |pool: &str| Gauge::from("pool_liquidity").with(labels! {"pool" => pool});
+        let mut expiry = SeriesExpiry::new();
+        let mut prom_ops = PrometheusOperations::default().with_ttl_blocks("pool_liquidity", 10);
+        prom_ops.push(pool("0xa").set(1.0));
+        prom_ops.push(pool("0xb").set(2.0));
+        prom_ops.push(pool("0xc").set(3.0));
+        prom_ops.push(Gauge::from("pools").set(3.0));
+        expiry.track(1, 0, &prom_ops);
+        assert_eq!(expiry.len(), 3);
+
+        let mut prom_ops = PrometheusOperations::default();
+        prom_ops.push(pool("0xa").remove(labels! {"pool" => "0xa"}));
+        expiry.track(2, 0, &prom_ops);
+        assert_eq!(expiry.len(), 2);
+
+        let mut prom_ops = PrometheusOperations::default().snapshot("pool_liquidity");
+        prom_ops.push(pool("0xb").set(2.0));
+        expiry.track(3, 0, &prom_ops);
+        assert_eq!(expiry.len(), 1);
+
+        let mut prom_ops = PrometheusOperations::default();
+        prom_ops.push(Gauge::from("pool_liquidity").reset());
+        expiry.track(4, 0, &prom_ops);
+        assert!(expiry.is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+use std::fmt::Write;
+
+use crate::{Labels, Registry, Value};
+
+impl Registry {
+    /// Render the registry in the Prometheus [text exposition format](https://prometheus.io/docs/instrumenting/exposition_formats/),
+    /// as served by the sink on `/metrics`.
+    ///
+    /// Families and series are sorted by name and labels, so the output is stable.
+    ///
+    /// ### Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use substreams_sink_prometheus::{Gauge, Registry};
+    /// let labels = HashMap::from([("label1".to_string(), "value1".to_string())]);
+    /// let mut registry = Registry::new();
+    /// registry.apply(&Gauge::from("gauge_name").with(labels).set(88.8).into()).unwrap();
+    /// assert_eq!(registry.to_text(), "# TYPE gauge_name gauge\ngauge_name{label1=\"value1\"} 88.8\n");
+    /// ```
+    pub fn to_text(&self) -> String {
+        self.to_text_filtered(|_| true)
+    }
+
+    /// Render only the families whose name matches `filter`
+    pub(crate) fn to_text_filtered<F: Fn(&str) -> bool>(&self, filter: F) -> String {
+        let mut out = String::new();
+        for (name, family) in self.families() {
+            if family.series.is_empty() || !filter(name) {
+                continue;
+            }
+            let _ = writeln!(out, "# TYPE {} {}", name, family.kind);
+            for (labels, value) in &family.series {
+                let mut sample = |suffix: &str, le: Option<&str>, value: f64| {
+                    write_sample(&mut out, name, suffix, labels, le, value)
+                };
+                match value {
+                    Value::Counter(value) | Value::Gauge(value) => sample("", None, *value),
+                    Value::Histogram(histogram) => {
+                        for (bound, count) in &histogram.buckets {
+                            sample("_bucket", Some(&format_value(*bound)), *count as f64);
+                        }
+                        sample("_bucket", Some("+Inf"), histogram.count as f64);
+                        sample("_sum", None, histogram.sum);
+                        sample("_count", None, histogram.count as f64);
+                    }
+                    Value::Summary(summary) => {
+                        sample("_sum", None, summary.sum);
+                        sample("_count", None, summary.count as f64);
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+fn write_sample(
+    out: &mut String,
+    name: &str,
+    suffix: &str,
+    labels: &Labels,
+    le: Option<&str>,
+    value: f64,
+) {
+    out.push_str(name);
+    out.push_str(suffix);
+    let pairs = labels.iter().map(|(k, v)| (k.as_str(), v.as_str()));
+    let pairs: Vec<(&str, &str)> = pairs.chain(le.map(|le| ("le", le))).collect();
+    if !pairs.is_empty() {
+        out.push('{');
+        for (i, (key, value)) in pairs.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            let _ = write!(out, "{}=\"{}\"", key, escape_label_value(value));
+        }
+        out.push('}');
+    }
+    let _ = writeln!(out, " {}", format_value(value));
+}
+
+/// Escape backslash, double-quote and line feed as required for label values.
+pub(crate) fn escape_label_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Format a sample value the way the Go client does (`+Inf`, `-Inf`, `NaN`).
+pub(crate) fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value == f64::INFINITY {
+        "+Inf".to_string()
+    } else if value == f64::NEG_INFINITY {
+        "-Inf".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Counter, Histogram, PrometheusOperations, Summary};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_to_text() {
+        let mut prom_ops: PrometheusOperations = Default::default();
+        let labels = HashMap::from([("symbol".to_string(), "a\"b\\c\nd".to_string())]);
+        prom_ops.push(Counter::from("transfers_total").with(labels).inc());
+        prom_ops.push(Histogram::from("fees").observe(0.7));
+        prom_ops.push(Summary::from("gas").observe(21000.0));
+
+        let mut registry = Registry::new().with_buckets(vec![0.5, 1.0]);
+        registry.apply_all(&prom_ops).unwrap();
+
+        assert_eq!(
+            registry.to_text(),
+            [
+                "# TYPE fees histogram",
+                "fees_bucket{le=\"0.5\"} 0",
+                "fees_bucket{le=\"1\"} 1",
+                "fees_bucket{le=\"+Inf\"} 1",
+                "fees_sum 0.7",
+                "fees_count 1",
+                "# TYPE gas summary",
+                "gas_sum 21000",
+                "gas_count 1",
+                "# TYPE transfers_total counter",
+                "transfers_total{symbol=\"a\\\"b\\\\c\\nd\"} 1",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(f64::NAN), "NaN");
+        assert_eq!(format_value(f64::NEG_INFINITY), "-Inf");
+        assert_eq!(format_value(-1.5), "-1.5");
+    }
+}
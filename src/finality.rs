@@ -0,0 +1,122 @@
+use std::collections::VecDeque;
+
+use crate::PrometheusOperations;
+
+/// Holds `final_only` batches until their block is final, other batches go through right away.
+///
+/// Batches of blocks rolled back by a fork are dropped before ever being released.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{Counter, FinalityBuffer, PrometheusOperations};
+/// let mut buffer = FinalityBuffer::new();
+/// let mut prom_ops = PrometheusOperations::default().final_only();
+/// prom_ops.push(Counter::from("settlement_volume_total").add(1000.0));
+///
+/// assert_eq!(buffer.push(12, prom_ops), None);
+/// assert_eq!(buffer.finalize(11).len(), 0);
+/// assert_eq!(buffer.finalize(12).len(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct FinalityBuffer {
+    pending: VecDeque<(u64, PrometheusOperations)>,
+}
+
+impl FinalityBuffer {
+    /// Create new empty FinalityBuffer
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Buffer the batch of block `number` if it is `final_only`, otherwise return it to be applied now
+    pub fn push(
+        &mut self,
+        number: u64,
+        operations: PrometheusOperations,
+    ) -> Option<PrometheusOperations> {
+        if !operations.final_only {
+            return Some(operations);
+        }
+        self.pending.push_back((number, operations));
+        None
+    }
+
+    /// Release the buffered batches of the blocks up to `final_block`, in block order
+    pub fn finalize(&mut self, final_block: u64) -> Vec<PrometheusOperations> {
+        let mut released = vec![];
+        while let Some((_, operations)) = self.pop_final(final_block) {
+            released.push(operations);
+        }
+        released
+    }
+
+    // Whether the oldest buffered batch is final
+    pub(crate) fn is_final(&self, final_block: u64) -> bool {
+        self.pending
+            .front()
+            .map_or(false, |(number, _)| *number <= final_block)
+    }
+
+    // Release the oldest buffered batch if its block is final
+    pub(crate) fn pop_final(&mut self, final_block: u64) -> Option<(u64, PrometheusOperations)> {
+        match self.pending.front() {
+            Some((number, _)) if *number <= final_block => self.pending.pop_front(),
+            _ => None,
+        }
+    }
+
+    /// Drop the buffered batches of the blocks after `last_valid_block`
+    pub fn undo(&mut self, last_valid_block: u64) {
+        while self
+            .pending
+            .back()
+            .map_or(false, |(number, _)| *number > last_valid_block)
+        {
+            self.pending.pop_back();
+        }
+    }
+
+    /// Number of buffered batches
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Counter;
+
+    fn batch(value: f64) -> PrometheusOperations {
+        let mut prom_ops = PrometheusOperations::default().final_only();
+        prom_ops.push(Counter::from("settlement_volume_total").add(value));
+        prom_ops
+    }
+
+    #[test]
+    fn test_finality_buffer() {
+        let mut buffer = FinalityBuffer::new();
+        let mut prom_ops = PrometheusOperations::default();
+        prom_ops.push(Counter::from("transfers_total").inc());
+        assert_eq!(buffer.push(1, prom_ops.clone()), Some(prom_ops));
+
+        for number in 1..=4 {
+            assert_eq!(buffer.push(number, batch(number as f64)), None);
+        }
+        assert_eq!(buffer.len(), 4);
+        assert_eq!(buffer.finalize(2), vec![batch(1.0), batch(2.0)]);
+
+        // Block #4 was orphaned
+        buffer.undo(3);
+        assert_eq!(buffer.finalize(10), vec![batch(3.0)]);
+        assert!(buffer.is_empty());
+    }
+}
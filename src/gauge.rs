@@ -1,11 +1,13 @@
 use std::collections::HashMap;
 
-use crate::{PrometheusOperation, prometheus_operation, GaugeOp, gauge_op};
+use crate::{prometheus_operation, Series, SeriesOperation, GaugeOp, gauge_op};
 
-#[derive(Eq, Debug, PartialEq, Default)]
+/// Builds the operations of a Gauge series.
+///
+/// The methods return a `SeriesOperation` sharing the name and labels interned by `from` and `with`, see `Series`.
+#[derive(Clone, Eq, Debug, PartialEq, Default)]
 pub struct Gauge {
-    pub name: String,
-    pub labels: HashMap<String, String>,
+    series: Series,
 }
 
 impl Gauge {
@@ -20,8 +22,7 @@ impl Gauge {
     #[must_use]
     pub fn from(name: &str) -> Self {
         Self {
-            name: name.to_string(),
-            labels: Default::default(),
+            series: Series::from_parts(name.to_string(), Default::default()),
         }
     }
 
@@ -38,10 +39,22 @@ impl Gauge {
     /// ```
     #[inline]
     pub fn with(mut self, labels: HashMap<String, String>) -> Self {
-        self.labels = labels;
+        self.series = self.series.with_labels(labels);
         self
     }
 
+    /// Metric name of the Gauge
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.series.name()
+    }
+
+    /// Labels set by `with`
+    #[inline]
+    pub fn labels(&self) -> &HashMap<String, String> {
+        self.series.labels()
+    }
+
     /// Sets the Gauge to an arbitrary value.
     ///
     /// ### Example
@@ -52,16 +65,8 @@ impl Gauge {
     /// ```
     #[inline]
     #[must_use]
-    pub fn set(&mut self, value: f64) -> PrometheusOperation {
-        let op = GaugeOp {
-            value,
-            operation: gauge_op::Operation::Set.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: self.labels.to_owned(),
-            operation: Some(prometheus_operation::Operation::Gauge(op)),
-        }
+    pub fn set(&mut self, value: f64) -> SeriesOperation {
+        self.operation(GaugeOp::set(value))
     }
 
     /// Increments the Gauge by 1.
@@ -74,16 +79,8 @@ impl Gauge {
     /// ```
     #[inline]
     #[must_use]
-    pub fn inc(&mut self) -> PrometheusOperation {
-        let op = GaugeOp {
-            value: 1.0,
-            operation: gauge_op::Operation::Inc.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: self.labels.to_owned(),
-            operation: Some(prometheus_operation::Operation::Gauge(op)),
-        }
+    pub fn inc(&mut self) -> SeriesOperation {
+        self.operation(GaugeOp::inc())
     }
 
     /// Decrements the Gauge by 1.
@@ -96,16 +93,8 @@ impl Gauge {
     /// ```
     #[inline]
     #[must_use]
-    pub fn dec(&mut self) -> PrometheusOperation {
-        let op = GaugeOp {
-            value: 1.0,
-            operation: gauge_op::Operation::Dec.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: self.labels.to_owned(),
-            operation: Some(prometheus_operation::Operation::Gauge(op)),
-        }
+    pub fn dec(&mut self) -> SeriesOperation {
+        self.operation(GaugeOp::dec())
     }
 
     /// Adds an arbitrary value to a Gauge. (The value can be negative, resulting in a decrease of the Gauge.)
@@ -118,16 +107,8 @@ impl Gauge {
     /// ```
     #[inline]
     #[must_use]
-    pub fn add(&mut self, value: f64) -> PrometheusOperation {
-        let op = GaugeOp {
-            value,
-            operation: gauge_op::Operation::Add.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: self.labels.to_owned(),
-            operation: Some(prometheus_operation::Operation::Gauge(op)),
-        }
+    pub fn add(&mut self, value: f64) -> SeriesOperation {
+        self.operation(GaugeOp::add(value))
     }
 
     /// Subtracts arbitrary value from the Gauge. (The value can be negative, resulting in an increase of the Gauge.)
@@ -140,16 +121,8 @@ impl Gauge {
     /// ```
     #[inline]
     #[must_use]
-    pub fn sub(&mut self, value: f64) -> PrometheusOperation {
-        let op = GaugeOp {
-            value,
-            operation: gauge_op::Operation::Sub.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: self.labels.to_owned(),
-            operation: Some(prometheus_operation::Operation::Gauge(op)),
-        }
+    pub fn sub(&mut self, value: f64) -> SeriesOperation {
+        self.operation(GaugeOp::sub(value))
     }
 
     /// SetToCurrentTime sets the Gauge to the current Unix time in seconds.
@@ -162,15 +135,26 @@ impl Gauge {
     /// ```
     #[inline]
     #[must_use]
-    pub fn set_to_current_time(&mut self) -> PrometheusOperation {
-        let op = GaugeOp {
-            value: f64::NAN,
-            operation: gauge_op::Operation::SetToCurrentTime.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: self.labels.to_owned(),
-            operation: Some(prometheus_operation::Operation::Gauge(op)),
+    pub fn set_to_current_time(&mut self) -> SeriesOperation {
+        self.operation(GaugeOp::set_to_current_time())
+    }
+
+    /// Initialize the metrics for the given combination of labels to zero
+    ///
+    /// ### Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use substreams_sink_prometheus::{PrometheusOperations, Gauge};
+    /// let mut prom_ops: PrometheusOperations = Default::default();
+    /// let labels = HashMap::from([("label1".to_string(), "value1".to_string())]);
+    /// prom_ops.push(Gauge::from("gauge_name").zero(labels));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn zero(&mut self, labels: HashMap<String, String>) -> SeriesOperation {
+        SeriesOperation {
+            series: Series::from_parts(self.name().to_owned(), labels),
+            operation: Some(prometheus_operation::Operation::Gauge(GaugeOp::zero())),
         }
     }
 
@@ -186,15 +170,10 @@ impl Gauge {
     /// ```
     #[inline]
     #[must_use]
-    pub fn remove(&mut self, labels: HashMap<String, String>) -> PrometheusOperation {
-        let op = GaugeOp {
-            value: f64::NAN,
-            operation: gauge_op::Operation::Remove.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels,
-            operation: Some(prometheus_operation::Operation::Gauge(op)),
+    pub fn remove(&mut self, labels: HashMap<String, String>) -> SeriesOperation {
+        SeriesOperation {
+            series: Series::from_parts(self.name().to_owned(), labels),
+            operation: Some(prometheus_operation::Operation::Gauge(GaugeOp::remove())),
         }
     }
 
@@ -208,15 +187,110 @@ impl Gauge {
     /// ```
     #[inline]
     #[must_use]
-    pub fn reset(&mut self) -> PrometheusOperation {
-        let op = GaugeOp {
+    pub fn reset(&mut self) -> SeriesOperation {
+        SeriesOperation {
+            series: Series::from_parts(self.name().to_owned(), Default::default()),
+            operation: Some(prometheus_operation::Operation::Gauge(GaugeOp::reset())),
+        }
+    }
+
+    #[inline]
+    fn operation(&self, op: GaugeOp) -> SeriesOperation {
+        SeriesOperation {
+            series: self.series.clone(),
+            operation: Some(prometheus_operation::Operation::Gauge(op)),
+        }
+    }
+}
+
+impl GaugeOp {
+    /// Sets the Gauge to an arbitrary value.
+    #[inline]
+    #[must_use]
+    pub fn set(value: f64) -> Self {
+        Self {
+            value,
+            operation: gauge_op::Operation::Set.into(),
+        }
+    }
+
+    /// Increments the Gauge by 1.
+    #[inline]
+    #[must_use]
+    pub fn inc() -> Self {
+        Self {
+            value: 1.0,
+            operation: gauge_op::Operation::Inc.into(),
+        }
+    }
+
+    /// Decrements the Gauge by 1.
+    #[inline]
+    #[must_use]
+    pub fn dec() -> Self {
+        Self {
+            value: 1.0,
+            operation: gauge_op::Operation::Dec.into(),
+        }
+    }
+
+    /// Adds an arbitrary value to a Gauge. (The value can be negative, resulting in a decrease of the Gauge.)
+    #[inline]
+    #[must_use]
+    pub fn add(value: f64) -> Self {
+        Self {
+            value,
+            operation: gauge_op::Operation::Add.into(),
+        }
+    }
+
+    /// Subtracts arbitrary value from the Gauge. (The value can be negative, resulting in an increase of the Gauge.)
+    #[inline]
+    #[must_use]
+    pub fn sub(value: f64) -> Self {
+        Self {
+            value,
+            operation: gauge_op::Operation::Sub.into(),
+        }
+    }
+
+    /// SetToCurrentTime sets the Gauge to the current Unix time in seconds.
+    #[inline]
+    #[must_use]
+    pub fn set_to_current_time() -> Self {
+        Self {
+            value: f64::NAN,
+            operation: gauge_op::Operation::SetToCurrentTime.into(),
+        }
+    }
+
+    /// Initialize the metrics for the given combination of labels to zero
+    #[inline]
+    #[must_use]
+    pub fn zero() -> Self {
+        Self {
+            value: f64::NAN,
+            operation: gauge_op::Operation::Zero.into(),
+        }
+    }
+
+    /// Remove metrics for the given label values
+    #[inline]
+    #[must_use]
+    pub fn remove() -> Self {
+        Self {
+            value: f64::NAN,
+            operation: gauge_op::Operation::Remove.into(),
+        }
+    }
+
+    /// Reset gauge values
+    #[inline]
+    #[must_use]
+    pub fn reset() -> Self {
+        Self {
             value: f64::NAN,
             operation: gauge_op::Operation::Reset.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: Default::default(),
-            operation: Some(prometheus_operation::Operation::Gauge(op)),
         }
     }
 }
@@ -224,7 +298,10 @@ impl Gauge {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::series::tests::{arb_labels, arb_name, check_operations};
     use crate::PrometheusOperations;
+    use prost::Message;
+    use proptest::prelude::*;
     use std::collections::HashMap;
 
     #[test]
@@ -244,4 +321,34 @@ mod tests {
 
         assert_eq!(prom_ops.operations.len(), 8);
     }
+
+    #[test]
+    fn test_gauge_encode() {
+        let labels = HashMap::from([("label1".to_string(), "value1".to_string())]);
+        let mut gauge = Gauge::from("gauge_name").with(labels);
+
+        let mut buf = vec![];
+        gauge.set(88.8).encode(&mut buf);
+        gauge.inc().encode(&mut buf);
+        let prom_ops = PrometheusOperations::decode(buf.as_slice()).unwrap();
+        assert_eq!(prom_ops.operations, vec![gauge.set(88.8), gauge.inc()]);
+    }
+
+    proptest! {
+        #[test]
+        fn test_gauge_operations(name in arb_name(), labels in arb_labels(), value in any::<f64>()) {
+            let mut gauge = Gauge::from(&name).with(labels.clone());
+            check_operations(&name, &labels, [
+                (gauge.set(value), gauge_op::Operation::Set as i32, Some(value)),
+                (gauge.inc(), gauge_op::Operation::Inc as i32, Some(1.0)),
+                (gauge.dec(), gauge_op::Operation::Dec as i32, Some(1.0)),
+                (gauge.add(value), gauge_op::Operation::Add as i32, Some(value)),
+                (gauge.sub(value), gauge_op::Operation::Sub as i32, Some(value)),
+                (gauge.set_to_current_time(), gauge_op::Operation::SetToCurrentTime as i32, None),
+                (gauge.zero(labels.clone()), gauge_op::Operation::Zero as i32, None),
+                (gauge.remove(labels.clone()), gauge_op::Operation::Remove as i32, None),
+                (gauge.reset(), gauge_op::Operation::Reset as i32, None),
+            ])?;
+        }
+    }
 }
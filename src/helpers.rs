@@ -1,18 +1,226 @@
-use crate::{PrometheusOperation, PrometheusOperations};
+use prost::{DecodeError, Message};
+
+use std::collections::HashMap;
+
+use crate::{
+    series::{encode_sorted, encode_sorted_map, encode_sorted_u64_map},
+    PrometheusOperation, PrometheusOperations,
+};
 
 impl PrometheusOperations {
-    pub fn push(&mut self, operation: PrometheusOperation) {
-        self.operations.push(operation);
+    pub fn push(&mut self, operation: impl Into<PrometheusOperation>) {
+        self.operations.push(operation.into());
+    }
+
+    pub fn extend<T: Into<PrometheusOperation>>(&mut self, operations: impl IntoIterator<Item = T>) {
+        self.operations.extend(operations.into_iter().map(Into::into));
+    }
+
+    /// Apply the operations only once their block is final, see `FinalityBuffer`.
+    /// Use it for metrics that must never be partially applied and then reverted on a fork.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_prometheus::{Counter, PrometheusOperations};
+    /// let mut prom_ops = PrometheusOperations::default().final_only();
+    /// prom_ops.push(Counter::from("settlement_volume_total").add(1000.0));
+    /// assert!(prom_ops.final_only);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn final_only(mut self) -> Self {
+        self.final_only = true;
+        self
+    }
+
+    /// Set the Pushgateway job of the batch, see `PushgatewayEncoder`
+    #[inline]
+    #[must_use]
+    pub fn with_job(mut self, job: &str) -> Self {
+        self.job = job.to_string();
+        self
+    }
+
+    /// Set the Pushgateway grouping key of the batch
+    #[inline]
+    #[must_use]
+    pub fn with_grouping_key(mut self, grouping_key: HashMap<String, String>) -> Self {
+        self.grouping_key = grouping_key;
+        self
+    }
+
+    /// Replace every metric of the Pushgateway group, instead of only the metrics with the same names as the batch
+    #[inline]
+    #[must_use]
+    pub fn replace_group(mut self) -> Self {
+        self.replace_group = true;
+        self
     }
 
-    pub fn extend(&mut self, operations: Vec<PrometheusOperation>) {
-        self.operations.extend(operations);
+    /// Mark the series of the metric `name` in this batch as its complete set:
+    /// the other series of the metric are removed once the batch is applied (ex: top-N leaderboards).
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_prometheus::{labels, Gauge, PrometheusOperations};
+    /// let mut prom_ops = PrometheusOperations::default().snapshot("top_pools_volume");
+    /// for (pool, volume) in [("0xa", 10.0), ("0xb", 5.0)] {
+    ///     prom_ops.push(Gauge::from("top_pools_volume").with(labels! {"pool" => pool}).set(volume));
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn snapshot(mut self, name: &str) -> Self {
+        self.snapshot.push(name.to_string());
+        self
+    }
+
+    /// Remove the series of the metric `name` once they receive no update for `blocks` blocks.
+    ///
+    /// `Registry` and `ReversibleRegistry` don't enforce it: the sink tracks the series with `SeriesExpiry`
+    /// and applies the `remove` operations it returns.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_prometheus::PrometheusOperations;
+    /// let prom_ops = PrometheusOperations::default().with_ttl_blocks("pool_liquidity", 7200);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_ttl_blocks(mut self, name: &str, blocks: u64) -> Self {
+        self.ttl_blocks.insert(name.to_string(), blocks);
+        self
+    }
+
+    /// Remove the series of the metric `name` once they receive no update for `seconds` seconds.
+    ///
+    /// Enforced by the sink like `with_ttl_blocks`.
+    #[inline]
+    #[must_use]
+    pub fn with_ttl_seconds(mut self, name: &str, seconds: u64) -> Self {
+        self.ttl_seconds.insert(name.to_string(), seconds);
+        self
+    }
+
+    /// Encode with labels sorted by name, unlike `encode_to_vec` which follows the `HashMap` iteration order.
+    /// The same operations always encode to the same bytes, so outputs are reproducible and can be hashed or diffed.
+    ///
+    /// Return `PrometheusOperationsWriter::from(prom_ops)` from a map handler to get the same encoding.
+    pub fn encode_sorted(&self) -> Vec<u8> {
+        let mut buf = vec![];
+        for operation in &self.operations {
+            encode_sorted(operation, &mut buf);
+        }
+        if self.final_only {
+            prost::encoding::bool::encode(2, &true, &mut buf);
+        }
+        if !self.job.is_empty() {
+            prost::encoding::string::encode(3, &self.job, &mut buf);
+        }
+        encode_sorted_map(4, &self.grouping_key, &mut buf);
+        if self.replace_group {
+            prost::encoding::bool::encode(5, &true, &mut buf);
+        }
+        prost::encoding::string::encode_repeated(6, &self.snapshot, &mut buf);
+        encode_sorted_u64_map(7, &self.ttl_blocks, &mut buf);
+        encode_sorted_u64_map(8, &self.ttl_seconds, &mut buf);
+        buf
+    }
+
+    /// Decode consecutive length-delimited batches, as written by `encode_length_delimited` (one per block)
+    pub fn decode_stream(mut buf: &[u8]) -> Result<Vec<PrometheusOperations>, DecodeError> {
+        let mut batches = vec![];
+        while !buf.is_empty() {
+            batches.push(PrometheusOperations::decode_length_delimited(&mut buf)?);
+        }
+        Ok(batches)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Counter, PrometheusOperations};
+    use crate::{
+        prometheus_operation, AggregatedObservations, Counter, CounterOp, GaugeOp, HistogramOp,
+        PrometheusOperation, PrometheusOperations, PrometheusOperationsWriter, SummaryOp,
+    };
+    use prost::Message;
+    use proptest::{collection::hash_map, prelude::*};
+
+    // Any operation of the four types, including unknown enum values, NaN and infinite values
+    fn arb_operation() -> impl Strategy<Value = PrometheusOperation> {
+        let operation = prop_oneof![
+            (any::<f64>(), any::<i32>())
+                .prop_map(|(value, operation)| prometheus_operation::Operation::Counter(CounterOp { value, operation })),
+            (any::<f64>(), any::<i32>())
+                .prop_map(|(value, operation)| prometheus_operation::Operation::Gauge(GaugeOp { value, operation })),
+            (any::<f64>(), any::<i32>(), proptest::option::of(arb_aggregated()), arb_values(), arb_counts())
+                .prop_map(|(value, operation, aggregated, values, counts)| prometheus_operation::Operation::Histogram(HistogramOp { value, operation, aggregated, values, counts })),
+            (any::<f64>(), any::<i32>(), proptest::option::of(arb_aggregated()), arb_values(), arb_counts())
+                .prop_map(|(value, operation, aggregated, values, counts)| prometheus_operation::Operation::Summary(SummaryOp { value, operation, aggregated, values, counts })),
+        ];
+        (any::<String>(), hash_map(any::<String>(), any::<String>(), 0..8), proptest::option::of(operation))
+            .prop_map(|(name, labels, operation)| PrometheusOperation { name, labels, operation })
+    }
+
+    fn arb_values() -> impl Strategy<Value = Vec<f64>> {
+        proptest::collection::vec(any::<f64>(), 0..4)
+    }
+
+    fn arb_counts() -> impl Strategy<Value = Vec<u64>> {
+        proptest::collection::vec(any::<u64>(), 0..4)
+    }
+
+    fn arb_aggregated() -> impl Strategy<Value = AggregatedObservations> {
+        let counts = proptest::collection::vec(any::<u64>(), 0..4);
+        (proptest::collection::vec(any::<f64>(), 0..4), counts, any::<f64>(), any::<u64>())
+            .prop_map(|(bounds, bucket_counts, sum, count)| AggregatedObservations { bounds, bucket_counts, sum, count })
+    }
+
+    proptest! {
+        // NaN values can't be compared, so compare the encodings with labels sorted instead
+        #[test]
+        fn test_round_trip(
+            operations in proptest::collection::vec(arb_operation(), 0..16),
+            final_only in any::<bool>(),
+            job in any::<String>(),
+            grouping_key in hash_map(any::<String>(), any::<String>(), 0..4),
+            replace_group in any::<bool>(),
+            snapshot in proptest::collection::vec(any::<String>(), 0..4),
+            ttl_blocks in proptest::collection::hash_map(any::<String>(), any::<u64>(), 0..4),
+            ttl_seconds in proptest::collection::hash_map(any::<String>(), any::<u64>(), 0..4),
+        ) {
+            let prom_ops = PrometheusOperations {
+                operations,
+                final_only,
+                job,
+                grouping_key,
+                replace_group,
+                snapshot,
+                ttl_blocks,
+                ttl_seconds,
+            };
+            let encoded = prom_ops.encode_sorted();
+
+            let decoded = PrometheusOperations::decode(prom_ops.encode_to_vec().as_slice()).unwrap();
+            prop_assert_eq!(decoded.operations.len(), prom_ops.operations.len());
+            prop_assert_eq!(decoded.encode_sorted(), encoded.clone());
+            prop_assert_eq!(decoded.encoded_len(), prom_ops.encoded_len());
+
+            let decoded = PrometheusOperations::decode(encoded.as_slice()).unwrap();
+            prop_assert_eq!(decoded.final_only, prom_ops.final_only);
+            prop_assert_eq!(&decoded.job, &prom_ops.job);
+            prop_assert_eq!(&decoded.grouping_key, &prom_ops.grouping_key);
+            prop_assert_eq!(decoded.replace_group, prom_ops.replace_group);
+            prop_assert_eq!(&decoded.snapshot, &prom_ops.snapshot);
+            prop_assert_eq!(&decoded.ttl_blocks, &prom_ops.ttl_blocks);
+            prop_assert_eq!(&decoded.ttl_seconds, &prom_ops.ttl_seconds);
+
+            let writer = PrometheusOperationsWriter::from(prom_ops);
+            prop_assert_eq!(writer.as_bytes(), encoded.as_slice());
+            let decoded = PrometheusOperationsWriter::decode(encoded.as_slice()).unwrap();
+            prop_assert_eq!(decoded.into_bytes(), encoded);
+        }
+    }
 
     #[test]
     fn test_push() {
@@ -32,4 +240,31 @@ mod tests {
 
         assert_eq!(prom_ops.operations.len(), 2);
     }
+
+    #[test]
+    fn test_encode_sorted() {
+        let labels: Vec<(String, String)> = (0..10).map(|i| (format!("label{i}"), i.to_string())).collect();
+        let mut prom_ops: PrometheusOperations = Default::default();
+        let mut counter = Counter::from("custom_counter").with(labels.iter().cloned().collect());
+        prom_ops.push(counter.inc());
+        let mut reversed: PrometheusOperations = Default::default();
+        let mut counter = Counter::from("custom_counter").with(labels.into_iter().rev().collect());
+        reversed.push(counter.inc());
+
+        assert_eq!(prom_ops.encode_sorted(), reversed.encode_sorted());
+        assert_eq!(PrometheusOperations::decode(prom_ops.encode_sorted().as_slice()).unwrap(), prom_ops);
+    }
+
+    #[test]
+    fn test_decode_stream() {
+        let mut prom_ops: PrometheusOperations = Default::default();
+        let mut counter = Counter::from("custom_counter");
+        prom_ops.push(counter.inc());
+
+        let mut buf = vec![];
+        prom_ops.encode_length_delimited(&mut buf).unwrap();
+        prom_ops.encode_length_delimited(&mut buf).unwrap();
+        assert_eq!(PrometheusOperations::decode_stream(&buf).unwrap(), vec![prom_ops.clone(), prom_ops]);
+        assert!(PrometheusOperations::decode_stream(&buf[..buf.len() - 1]).is_err());
+    }
 }
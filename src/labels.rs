@@ -1,7 +1,148 @@
+use std::collections::HashMap;
+
+/// Build a label `HashMap` from `name => value` pairs, converting both with `to_string`.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{labels, Counter};
+/// let counter = Counter::from("transfers_total").with(labels! {"token" => "USDT", "decimals" => 6});
+/// assert_eq!(counter.labels()["decimals"], "6");
+/// ```
+#[macro_export]
+macro_rules! labels {
+    ($($name:expr => $value:expr),* $(,)?) => {
+        ::std::collections::HashMap::<::std::string::String, ::std::string::String>::from([
+            $(($name.to_string(), $value.to_string())),*
+        ])
+    };
+}
+
+/// Maximum length in bytes of a label value kept by the default `LabelSanitizer`
+pub const MAX_LABEL_VALUE_LEN: usize = 128;
+
+/// Cleans label values coming from on-chain data (ex: ERC-20 `symbol()`) before they are passed to `with`.
+///
+/// - strips control characters (newlines, tabs, NUL, ...) and invalid UTF-8 sequences
+/// - trims surrounding whitespace
+/// - lowercases `0x` hex values so the same address always maps to the same series
+/// - truncates values longer than `max_len` bytes
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::LabelSanitizer;
+/// let sanitizer = LabelSanitizer::new().with_max_len(8);
+/// assert_eq!(sanitizer.sanitize(" USD\nT\0 "), "USDT");
+/// assert_eq!(sanitizer.sanitize("0xdAC17F"), "0xdac17f");
+/// assert_eq!(sanitizer.sanitize_bytes(b"WETH\xff\xfe"), "WETH");
+/// assert_eq!(sanitizer.sanitize("a very long symbol"), "a very l");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabelSanitizer {
+    max_len: usize,
+}
+
+impl Default for LabelSanitizer {
+    fn default() -> Self {
+        Self {
+            max_len: MAX_LABEL_VALUE_LEN,
+        }
+    }
+}
+
+impl LabelSanitizer {
+    /// Create new LabelSanitizer truncating values to `MAX_LABEL_VALUE_LEN` bytes
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the maximum length in bytes of label values
+    #[inline]
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Sanitize a single label value
+    pub fn sanitize(&self, value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !c.is_control() && *c != char::REPLACEMENT_CHARACTER)
+            .collect();
+        let mut value = value.trim().to_string();
+        if is_hex(&value) {
+            value.make_ascii_lowercase();
+        }
+        if value.len() > self.max_len {
+            let mut end = self.max_len;
+            while !value.is_char_boundary(end) {
+                end -= 1;
+            }
+            value.truncate(end);
+        }
+        value
+    }
+
+    /// Sanitize raw bytes, dropping invalid UTF-8 sequences
+    pub fn sanitize_bytes(&self, value: &[u8]) -> String {
+        self.sanitize(&String::from_utf8_lossy(value))
+    }
+
+    /// Sanitize every label name and value
+    pub fn sanitize_labels(&self, labels: HashMap<String, String>) -> HashMap<String, String> {
+        labels
+            .into_iter()
+            .map(|(key, value)| (sanitize_label_name(&key), self.sanitize(&value)))
+            .collect()
+    }
+}
+
+/// Sanitize a label value with the default `LabelSanitizer`
+pub fn sanitize_label_value(value: &str) -> String {
+    LabelSanitizer::new().sanitize(value)
+}
+
+/// Sanitize every label name and value with the default `LabelSanitizer`
+///
+/// ### Example
+/// ```
+/// use std::collections::HashMap;
+/// use substreams_sink_prometheus::{sanitize_labels, Gauge};
+/// let labels = HashMap::from([("token-symbol".to_string(), "USDT\n".to_string())]);
+/// let gauge = Gauge::from("supply").with(sanitize_labels(labels));
+/// assert_eq!(gauge.labels()["token_symbol"], "USDT");
+/// ```
+pub fn sanitize_labels(labels: HashMap<String, String>) -> HashMap<String, String> {
+    LabelSanitizer::new().sanitize_labels(labels)
+}
+
+/// Replace the characters not allowed in label names (`[a-zA-Z_][a-zA-Z0-9_]*`) with `_`
+pub fn sanitize_label_name(name: &str) -> String {
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if sanitized.is_empty() || sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        sanitized.insert(0, '_');
+    }
+    sanitized
+}
+
+fn is_hex(value: &str) -> bool {
+    match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(digits) => !digits.is_empty() && digits.chars().all(|c| c.is_ascii_hexdigit()),
+        None => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::{Counter, PrometheusOperations};
-    use std::collections::HashMap;
 
     #[test]
     fn test_labels_counter_1() {
@@ -28,4 +169,29 @@ mod tests {
         let counter = Counter::from("custom_counter");
         prom_ops.push(counter.with(labels).inc());
     }
+
+    #[test]
+    fn test_sanitize_labels() {
+        let labels = HashMap::from([
+            ("symbol".to_string(), "\tUNI-V2\r\n".to_string()),
+            (
+                "0holder".to_string(),
+                "0xDAC17F958D2EE523A2206206994597C13D831EC7".to_string(),
+            ),
+            ("name".to_string(), "é".repeat(100)),
+        ]);
+        let labels = sanitize_labels(labels);
+
+        assert_eq!(labels["symbol"], "UNI-V2");
+        assert_eq!(
+            labels["_0holder"],
+            "0xdac17f958d2ee523a2206206994597c13d831ec7"
+        );
+        assert_eq!(labels["name"], "é".repeat(64));
+        assert_eq!(sanitize_label_name(""), "_");
+        assert_eq!(
+            LabelSanitizer::new().sanitize_bytes(&[0x55, 0xc3, 0x4e, 0x49]),
+            "UNI"
+        );
+    }
 }
@@ -140,5 +140,40 @@ mod summary;
 pub use self::summary::*;
 mod histogram;
 pub use self::histogram::*;
+mod namespace;
+pub use self::namespace::*;
+mod series;
+pub use self::series::*;
+mod writer;
+pub use self::writer::*;
+mod registry;
+pub use self::registry::*;
+mod exposition;
+mod lint;
+pub use self::lint::*;
+mod cardinality;
+pub use self::cardinality::*;
 mod labels;
 pub use self::labels::*;
+mod reorg;
+pub use self::reorg::*;
+mod finality;
+pub use self::finality::*;
+mod pushgateway;
+pub use self::pushgateway::*;
+mod expiry;
+pub use self::expiry::*;
+mod window;
+pub use self::window::*;
+mod accumulator;
+pub use self::accumulator::*;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+#[cfg(feature = "substreams")]
+mod store;
+#[cfg(feature = "substreams")]
+pub use self::store::*;
+#[cfg(feature = "substreams")]
+mod scalar;
+#[cfg(feature = "substreams")]
+pub use self::scalar::*;
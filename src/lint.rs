@@ -0,0 +1,264 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::{prometheus_operation, MetricType, PrometheusOperation, PrometheusOperations};
+
+/// Unit suffixes accepted by default, as recommended by the Prometheus [naming conventions](https://prometheus.io/docs/practices/naming/).
+pub const BASE_UNITS: [&str; 10] = [
+    "seconds", "bytes", "ratio", "meters", "grams", "joules", "volts", "amperes", "celsius", "info",
+];
+
+// Unit suffixes that should be converted to a base unit, with the unit to use instead.
+const NON_BASE_UNITS: [(&str, &str); 16] = [
+    ("ms", "seconds"),
+    ("millis", "seconds"),
+    ("milliseconds", "seconds"),
+    ("microseconds", "seconds"),
+    ("nanoseconds", "seconds"),
+    ("minutes", "seconds"),
+    ("hours", "seconds"),
+    ("days", "seconds"),
+    ("bits", "bytes"),
+    ("kb", "bytes"),
+    ("kilobytes", "bytes"),
+    ("mb", "bytes"),
+    ("megabytes", "bytes"),
+    ("gb", "bytes"),
+    ("gigabytes", "bytes"),
+    ("percent", "ratio"),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Rule {
+    /// Counter names should end with `_total`.
+    CounterSuffix,
+    /// Metric and label names should be snake_case.
+    CamelCase,
+    /// Metric names should end with their unit.
+    MissingUnit,
+    /// Units should be base units (seconds, bytes, ...).
+    NonBaseUnit,
+    /// Label values should not be unbounded identifiers (addresses, transaction hashes, ...).
+    UnboundedLabelValue,
+}
+
+impl Rule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Rule::CounterSuffix => "counter-suffix",
+            Rule::CamelCase => "camel-case",
+            Rule::MissingUnit => "missing-unit",
+            Rule::NonBaseUnit => "non-base-unit",
+            Rule::UnboundedLabelValue => "unbounded-label-value",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Warning {
+    pub name: String,
+    pub rule: Rule,
+    pub message: String,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} [{}]",
+            self.name,
+            self.message,
+            self.rule.as_str()
+        )
+    }
+}
+
+/// Checks metric and label names against the Prometheus best practices.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{Counter, Gauge, Linter, PrometheusOperations, Rule};
+/// let mut prom_ops: PrometheusOperations = Default::default();
+/// prom_ops.push(Counter::from("transfers").inc());
+/// prom_ops.push(Gauge::from("balance_wei").set(1.0));
+///
+/// let warnings = Linter::new().with_units(&["wei"]).lint(&prom_ops);
+/// assert_eq!(warnings.len(), 1);
+/// assert_eq!(warnings[0].rule, Rule::CounterSuffix);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Linter {
+    units: Vec<String>,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self {
+            units: BASE_UNITS.iter().map(|unit| unit.to_string()).collect(),
+        }
+    }
+}
+
+impl Linter {
+    /// Create new Linter accepting the `BASE_UNITS`
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Accept additional unit suffixes (ex: `wei`, `usd`)
+    #[inline]
+    pub fn with_units(mut self, units: &[&str]) -> Self {
+        self.units.extend(units.iter().map(|unit| unit.to_string()));
+        self
+    }
+
+    /// Lint every operation of the batch, each warning is reported once.
+    pub fn lint(&self, operations: &PrometheusOperations) -> Vec<Warning> {
+        let warnings: BTreeSet<Warning> = operations
+            .operations
+            .iter()
+            .flat_map(|operation| self.lint_operation(operation))
+            .collect();
+        warnings.into_iter().collect()
+    }
+
+    /// Lint a single operation
+    pub fn lint_operation(&self, operation: &PrometheusOperation) -> Vec<Warning> {
+        let name = operation.name.as_str();
+        let mut warnings = vec![];
+        let mut warn = |rule, message: String| {
+            warnings.push(Warning {
+                name: name.to_owned(),
+                rule,
+                message,
+            })
+        };
+
+        if has_uppercase(name) {
+            warn(
+                Rule::CamelCase,
+                "metric name should be snake_case".to_string(),
+            );
+        }
+        for (key, value) in &operation.labels {
+            if has_uppercase(key) {
+                warn(
+                    Rule::CamelCase,
+                    format!("label name {key:?} should be snake_case"),
+                );
+            }
+            if is_unbounded(value) {
+                warn(
+                    Rule::UnboundedLabelValue,
+                    format!("label {key:?} has an unbounded value {value:?}"),
+                );
+            }
+        }
+
+        let kind = match &operation.operation {
+            Some(prometheus_operation::Operation::Counter(_)) => MetricType::Counter,
+            Some(prometheus_operation::Operation::Gauge(_)) => MetricType::Gauge,
+            Some(prometheus_operation::Operation::Histogram(_)) => MetricType::Histogram,
+            Some(prometheus_operation::Operation::Summary(_)) => MetricType::Summary,
+            None => return warnings,
+        };
+        let lowercase = name.to_lowercase();
+        let unit_name = match kind {
+            MetricType::Counter => match lowercase.strip_suffix("_total") {
+                Some(unit_name) => unit_name,
+                None => {
+                    warn(
+                        Rule::CounterSuffix,
+                        "counter name should end with `_total`".to_string(),
+                    );
+                    &lowercase
+                }
+            },
+            _ => &lowercase,
+        };
+        let unit = unit_name.rsplit('_').next().unwrap_or_default();
+        if let Some((_, base)) = NON_BASE_UNITS
+            .iter()
+            .find(|(non_base, _)| *non_base == unit)
+        {
+            warn(
+                Rule::NonBaseUnit,
+                format!("unit `{unit}` should be converted to `{base}`"),
+            );
+        } else if kind != MetricType::Counter && !self.units.iter().any(|known| known == unit) {
+            warn(
+                Rule::MissingUnit,
+                "metric name should end with its unit (ex: `_seconds`, `_bytes`)".to_string(),
+            );
+        }
+        warnings
+    }
+}
+
+/// Lint the batch with the default `Linter`
+pub fn lint(operations: &PrometheusOperations) -> Vec<Warning> {
+    Linter::new().lint(operations)
+}
+
+fn has_uppercase(name: &str) -> bool {
+    name.chars().any(|c| c.is_ascii_uppercase())
+}
+
+// Hex addresses and hashes (`0x` + 40 or more hex digits), or 64 bare hex digits.
+fn is_unbounded(value: &str) -> bool {
+    let is_hex = |digits: &str| digits.chars().all(|c| c.is_ascii_hexdigit());
+    match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(digits) => digits.len() >= 40 && is_hex(digits),
+        None => value.len() >= 64 && is_hex(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Counter, Gauge, Histogram};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_lint() {
+        let mut prom_ops: PrometheusOperations = Default::default();
+        let holder = HashMap::from([(
+            "holder".to_string(),
+            "0xdAC17F958D2ee523a2206206994597C13D831ec7".to_string(),
+        )]);
+        prom_ops.push(Counter::from("transfers_total").inc());
+        prom_ops.push(Counter::from("swapCount").inc());
+        prom_ops.push(Counter::from("swapCount").inc());
+        prom_ops.push(Gauge::from("balance").with(holder).set(1.0));
+        prom_ops.push(Histogram::from("block_duration_ms").observe(1.0));
+        prom_ops.push(Histogram::from("block_duration_seconds").observe(1.0));
+
+        let warnings: Vec<(String, Rule)> = lint(&prom_ops)
+            .into_iter()
+            .map(|warning| (warning.name, warning.rule))
+            .collect();
+        assert_eq!(
+            warnings,
+            vec![
+                ("balance".to_string(), Rule::MissingUnit),
+                ("balance".to_string(), Rule::UnboundedLabelValue),
+                ("block_duration_ms".to_string(), Rule::NonBaseUnit),
+                ("swapCount".to_string(), Rule::CounterSuffix),
+                ("swapCount".to_string(), Rule::CamelCase),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_unbounded() {
+        assert!(is_unbounded(&format!("0x{}", "ab".repeat(32))));
+        assert!(is_unbounded(&"ab".repeat(32)));
+        assert!(!is_unbounded("0x1234"));
+        assert!(!is_unbounded("USDT"));
+    }
+}
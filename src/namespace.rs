@@ -0,0 +1,132 @@
+use crate::{Counter, Gauge, Histogram, Summary};
+
+/// Prefixes metric names with a namespace and an optional subsystem,
+/// like `prometheus.Opts{Namespace, Subsystem, Name}` in the Go client.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{Namespace, PrometheusOperations};
+/// let mut prom_ops: PrometheusOperations = Default::default();
+/// let uniswap = Namespace::from("uniswap").subsystem("v3");
+///
+/// let mut swaps = uniswap.counter("swaps_total");
+/// assert_eq!(swaps.name(), "uniswap_v3_swaps_total");
+/// prom_ops.push(swaps.inc());
+/// ```
+#[derive(Clone, Eq, Debug, PartialEq, Default)]
+pub struct Namespace {
+    pub namespace: String,
+    pub subsystem: String,
+}
+
+impl Namespace {
+    /// Create new Namespace
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_prometheus::Namespace;
+    /// let namespace = Namespace::from("uniswap");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from(namespace: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            subsystem: Default::default(),
+        }
+    }
+
+    /// Set the subsystem, placed between the namespace and the metric name
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_prometheus::Namespace;
+    /// let namespace = Namespace::from("uniswap").subsystem("v3");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn subsystem(&self, subsystem: &str) -> Self {
+        Self {
+            namespace: self.namespace.to_owned(),
+            subsystem: subsystem.to_string(),
+        }
+    }
+
+    /// Fully qualified metric name
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_prometheus::Namespace;
+    /// assert_eq!(Namespace::from("uniswap").subsystem("v3").name("pools"), "uniswap_v3_pools");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn name(&self, name: &str) -> String {
+        build_fq_name(&self.namespace, &self.subsystem, name)
+    }
+
+    /// Create new Counter with the fully qualified name
+    #[inline]
+    #[must_use]
+    pub fn counter(&self, name: &str) -> Counter {
+        Counter::from(&self.name(name))
+    }
+
+    /// Create new Gauge with the fully qualified name
+    #[inline]
+    #[must_use]
+    pub fn gauge(&self, name: &str) -> Gauge {
+        Gauge::from(&self.name(name))
+    }
+
+    /// Create new Histogram with the fully qualified name
+    #[inline]
+    #[must_use]
+    pub fn histogram(&self, name: &str) -> Histogram {
+        Histogram::from(&self.name(name))
+    }
+
+    /// Create new Summary with the fully qualified name
+    #[inline]
+    #[must_use]
+    pub fn summary(&self, name: &str) -> Summary {
+        Summary::from(&self.name(name))
+    }
+}
+
+/// Join the non-empty namespace, subsystem and name with `_`, same as `prometheus.BuildFQName`.
+/// Returns an empty string if `name` is empty.
+pub fn build_fq_name(namespace: &str, subsystem: &str, name: &str) -> String {
+    if name.is_empty() {
+        return String::new();
+    }
+    [namespace, subsystem, name]
+        .iter()
+        .filter(|part| !part.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namespace() {
+        let uniswap = Namespace::from("uniswap");
+        let v3 = uniswap.subsystem("v3");
+
+        assert_eq!(uniswap.gauge("pools").name(), "uniswap_pools");
+        assert_eq!(v3.histogram("swap_size").name(), "uniswap_v3_swap_size");
+        assert_eq!(v3.summary("fees").name(), "uniswap_v3_fees");
+        assert_eq!(
+            Namespace::from("")
+                .subsystem("v3")
+                .counter("swaps_total")
+                .name(),
+            "v3_swaps_total"
+        );
+        assert_eq!(build_fq_name("uniswap", "v3", ""), "");
+    }
+}
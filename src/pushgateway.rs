@@ -0,0 +1,373 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+#[cfg(feature = "pushgateway-client")]
+use std::io::{self, Read, Write};
+#[cfg(feature = "pushgateway-client")]
+use std::net::TcpStream;
+
+use crate::{registry::to_labels, reorg, Error, Labels, PrometheusOperations, Registry};
+
+const BASE64_URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PushMethod {
+    /// Replace every metric of the group
+    Put,
+    /// Replace the metrics of the group with the same names as the pushed ones
+    Post,
+}
+
+impl PushMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PushMethod::Put => "PUT",
+            PushMethod::Post => "POST",
+        }
+    }
+}
+
+/// Request pushing a batch to a [Pushgateway](https://github.com/prometheus/pushgateway).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PushRequest {
+    pub method: PushMethod,
+    /// `/metrics/job/<job>/<label>/<value>...`
+    pub path: String,
+    /// Metrics in the text exposition format
+    pub body: String,
+}
+
+impl PushRequest {
+    /// HTTP/1.1 request for the Pushgateway at `host` (`host:port`)
+    pub fn to_http(&self, host: &str) -> Vec<u8> {
+        let mut request = format!(
+            "{} {} HTTP/1.1\r\nHost: {host}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.method.as_str(),
+            self.path,
+            self.body.len()
+        )
+        .into_bytes();
+        request.extend_from_slice(self.body.as_bytes());
+        request
+    }
+
+    /// Send the request to the Pushgateway at `host` (`host:port`) and return the HTTP status code
+    ///
+    /// Blocking, over a plain `TcpStream`: requires the `pushgateway-client` feature.
+    #[cfg(feature = "pushgateway-client")]
+    pub fn send(&self, host: &str) -> io::Result<u16> {
+        let mut stream = TcpStream::connect(host)?;
+        stream.write_all(&self.to_http(host))?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        response
+            .split(' ')
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid HTTP response"))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PushError {
+    /// The batch has no `job`, required by the Pushgateway.
+    MissingJob,
+    Operation(Error),
+}
+
+impl fmt::Display for PushError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PushError::MissingJob => f.write_str("batch has no Pushgateway job"),
+            PushError::Operation(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for PushError {}
+
+impl From<Error> for PushError {
+    fn from(err: Error) -> Self {
+        PushError::Operation(err)
+    }
+}
+
+/// Turns batches into Pushgateway requests, keeping the state of each group (job and grouping key)
+/// since the Pushgateway expects current values, not operations.
+///
+/// Batches with `replace_group` start the group over and are pushed with `PUT`,
+/// others are applied on top of the group and only their metrics are pushed with `POST`.
+/// A `POST` can't delete metrics, so batches leaving one of their metrics without series
+/// push the whole group with `PUT` instead.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{labels, Counter, PrometheusOperations, PushMethod, PushgatewayEncoder};
+/// let mut encoder = PushgatewayEncoder::new();
+/// let mut prom_ops = PrometheusOperations::default()
+///     .with_job("uniswap")
+///     .with_grouping_key(labels! {"deployment" => "v3"});
+/// prom_ops.push(Counter::from("swaps_total").inc());
+///
+/// let request = encoder.encode(&prom_ops).unwrap();
+/// assert_eq!(request.method, PushMethod::Post);
+/// assert_eq!(request.path, "/metrics/job/uniswap/deployment/v3");
+/// assert_eq!(request.body, "# TYPE swaps_total counter\nswaps_total 1\n");
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct PushgatewayEncoder {
+    groups: BTreeMap<(String, Labels), Registry>,
+}
+
+impl PushgatewayEncoder {
+    /// Create new PushgatewayEncoder without any group
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Apply the batch to its group and build the request pushing it.
+    ///
+    /// The batch is applied entirely or not at all: on error, the group is left as it was.
+    pub fn encode(&mut self, operations: &PrometheusOperations) -> Result<PushRequest, PushError> {
+        if operations.job.is_empty() {
+            return Err(PushError::MissingJob);
+        }
+        let group = (
+            operations.job.to_owned(),
+            to_labels(&operations.grouping_key),
+        );
+        if operations.replace_group {
+            let mut registry = Registry::new();
+            registry.apply_all(operations)?;
+            self.groups.insert(group.clone(), registry);
+        } else {
+            // Applied in place, the batch rolls itself back on error
+            let existing = self.groups.contains_key(&group);
+            let registry = self.groups.entry(group.clone()).or_default();
+            if let Err(err) = reorg::apply(registry, operations) {
+                if !existing {
+                    self.groups.remove(&group);
+                }
+                return Err(err.into());
+            }
+        }
+        let registry = &self.groups[&group];
+
+        let names: HashSet<&str> = operations
+            .operations
+            .iter()
+            .map(|operation| operation.name.as_str())
+            .chain(operations.snapshot.iter().map(String::as_str))
+            .collect();
+        let emptied = names.iter().any(|name| {
+            registry
+                .families
+                .get(*name)
+                .map_or(true, |family| family.series.is_empty())
+        });
+        let (method, body) = if operations.replace_group || emptied {
+            (PushMethod::Put, registry.to_text())
+        } else {
+            let body = registry.to_text_filtered(|name| names.contains(name));
+            (PushMethod::Post, body)
+        };
+        Ok(PushRequest {
+            method,
+            path: push_path(&operations.job, &operations.grouping_key),
+            body,
+        })
+    }
+}
+
+/// Pushgateway URL path of a group, `/metrics/job/<job>/<label>/<value>...`
+///
+/// Values that are empty or not URL-safe are base64url encoded (`<label>@base64/<value>`).
+pub fn push_path(job: &str, grouping_key: &HashMap<String, String>) -> String {
+    let mut path = String::from("/metrics");
+    push_segment(&mut path, "job", job);
+    for (name, value) in to_labels(grouping_key) {
+        push_segment(&mut path, &name, &value);
+    }
+    path
+}
+
+fn push_segment(path: &mut String, name: &str, value: &str) {
+    let url_safe = |c: char| c.is_ascii_alphanumeric() || "-._~".contains(c);
+    if !value.is_empty() && value.chars().all(url_safe) {
+        path.push_str(&format!("/{name}/{value}"));
+    } else {
+        path.push_str(&format!("/{name}@base64/{}", base64_url(value.as_bytes())));
+    }
+}
+
+// base64url with padding, `=` for an empty value as expected by the Pushgateway
+fn base64_url(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return "=".to_string();
+    }
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, byte)| n | (*byte as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64_URL[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{labels, Counter, Gauge};
+
+    #[test]
+    fn test_push_path() {
+        assert_eq!(
+            push_path("uniswap", &labels! {"deployment" => "v3", "chain" => "eth"}),
+            "/metrics/job/uniswap/chain/eth/deployment/v3"
+        );
+        assert_eq!(
+            push_path("a/b", &labels! {"path" => "", "name" => "foo bar"}),
+            "/metrics/job@base64/YS9i/name@base64/Zm9vIGJhcg==/path@base64/="
+        );
+    }
+
+    #[test]
+    fn test_pushgateway_encoder() {
+        let mut encoder = PushgatewayEncoder::new();
+        let group = || {
+            PrometheusOperations::default()
+                .with_job("uniswap")
+                .with_grouping_key(labels! {"deployment" => "v3"})
+        };
+        let mut swaps = Counter::from("swaps_total");
+        let mut pools = Gauge::from("pools");
+
+        let mut prom_ops = group();
+        prom_ops.push(swaps.inc());
+        prom_ops.push(pools.set(10.0));
+        encoder.encode(&prom_ops).unwrap();
+
+        // Appends to the group: only swaps_total is pushed, with its cumulative value
+        let mut prom_ops = group();
+        prom_ops.push(swaps.inc());
+        let request = encoder.encode(&prom_ops).unwrap();
+        assert_eq!(request.method, PushMethod::Post);
+        assert_eq!(request.body, "# TYPE swaps_total counter\nswaps_total 2\n");
+
+        // Replaces the group
+        let mut prom_ops = group().replace_group();
+        prom_ops.push(swaps.inc());
+        let request = encoder.encode(&prom_ops).unwrap();
+        assert_eq!(request.method, PushMethod::Put);
+        assert_eq!(request.body, "# TYPE swaps_total counter\nswaps_total 1\n");
+
+        // Other groups are independent
+        let mut prom_ops = PrometheusOperations::default().with_job("uniswap");
+        prom_ops.push(swaps.inc());
+        let request = encoder.encode(&prom_ops).unwrap();
+        assert_eq!(request.path, "/metrics/job/uniswap");
+        assert_eq!(request.body, "# TYPE swaps_total counter\nswaps_total 1\n");
+
+        assert_eq!(
+            encoder.encode(&PrometheusOperations::default()),
+            Err(PushError::MissingJob)
+        );
+    }
+
+    #[test]
+    fn test_pushgateway_encoder_error() {
+        let mut encoder = PushgatewayEncoder::new();
+        let mut prom_ops = PrometheusOperations::default().with_job("uniswap");
+        prom_ops.push(Counter::from("swaps_total").inc());
+        encoder.encode(&prom_ops).unwrap();
+        let state = encoder.clone();
+
+        // Neither replaced nor half-applied
+        for replace_group in [false, true] {
+            let mut prom_ops = PrometheusOperations::default().with_job("uniswap");
+            prom_ops.replace_group = replace_group;
+            prom_ops.push(Counter::from("swaps_total").inc());
+            prom_ops.push(Gauge::from("swaps_total").set(1.0));
+            assert!(encoder.encode(&prom_ops).is_err());
+            assert_eq!(encoder, state);
+        }
+
+        // A new group isn't created
+        let mut prom_ops = PrometheusOperations::default().with_job("curve");
+        prom_ops.push(Counter::from("swaps_total").inc());
+        prom_ops.push(Gauge::from("swaps_total").set(1.0));
+        assert!(encoder.encode(&prom_ops).is_err());
+        assert_eq!(encoder, state);
+    }
+
+    #[test]
+    fn test_pushgateway_encoder_emptied() {
+        let mut encoder = PushgatewayEncoder::new();
+        let group = || PrometheusOperations::default().with_job("uniswap");
+        let pool = |pool: &str| Gauge::from("pool_liquidity").with(labels! {"pool" => pool});
+
+        let mut prom_ops = group();
+        prom_ops.push(pool("0xa").set(1.0));
+        prom_ops.push(Counter::from("swaps_total").inc());
+        encoder.encode(&prom_ops).unwrap();
+
+        // Removing the last series of pool_liquidity pushes the whole group to delete it
+        let mut prom_ops = group();
+        prom_ops.push(pool("0xa").remove(labels! {"pool" => "0xa"}));
+        let request = encoder.encode(&prom_ops).unwrap();
+        assert_eq!(request.method, PushMethod::Put);
+        assert_eq!(request.body, "# TYPE swaps_total counter\nswaps_total 1\n");
+
+        // Same for an empty snapshot
+        let request = encoder.encode(&group().snapshot("swaps_total")).unwrap();
+        assert_eq!(request.method, PushMethod::Put);
+        assert_eq!(request.body, "");
+
+        let mut prom_ops = group();
+        prom_ops.push(pool("0xb").set(2.0));
+        let request = encoder.encode(&prom_ops).unwrap();
+        assert_eq!(request.method, PushMethod::Post);
+    }
+
+    #[test]
+    #[cfg(feature = "pushgateway-client")]
+    fn test_push_request_send() {
+        use std::net::TcpListener;
+        use std::thread;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = listener.local_addr().unwrap().to_string();
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 1024];
+            // Headers then the body announced by Content-Length
+            while !String::from_utf8_lossy(&request).contains("swaps_total 1\n") {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let mut prom_ops = PrometheusOperations::default().with_job("uniswap");
+        prom_ops.push(Counter::from("swaps_total").inc());
+        let request = PushgatewayEncoder::new().encode(&prom_ops).unwrap();
+        assert_eq!(request.send(&host).unwrap(), 200);
+
+        let received = server.join().unwrap();
+        assert!(received.starts_with("POST /metrics/job/uniswap HTTP/1.1\r\n"));
+        assert!(received.ends_with("\r\n\r\n# TYPE swaps_total counter\nswaps_total 1\n"));
+    }
+}
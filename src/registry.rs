@@ -0,0 +1,997 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    counter_op, gauge_op, histogram_op, prometheus_operation, summary_op, AggregatedObservations,
+    CounterOp, GaugeOp, HistogramOp, PrometheusOperation, PrometheusOperations, SummaryOp,
+};
+
+/// Default histogram buckets, same as `prometheus.DefBuckets` in the Go client.
+pub const DEFAULT_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Label name -> value mappings of a single series, ordered by label name.
+pub type Labels = BTreeMap<String, String>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricType {
+    Counter,
+    Gauge,
+    Histogram,
+    Summary,
+}
+
+impl MetricType {
+    /// Name of the metric type as used in the `# TYPE` line of the text exposition format.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetricType::Counter => "counter",
+            MetricType::Gauge => "gauge",
+            MetricType::Histogram => "histogram",
+            MetricType::Summary => "summary",
+        }
+    }
+}
+
+impl fmt::Display for MetricType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistogramValue {
+    /// Cumulative bucket counts as (upper bound, count), the `+Inf` bucket is `count`.
+    pub buckets: Vec<(f64, u64)>,
+    pub sum: f64,
+    pub count: u64,
+}
+
+impl HistogramValue {
+    fn new(bounds: &[f64]) -> Self {
+        Self {
+            buckets: bounds.iter().map(|bound| (*bound, 0)).collect(),
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, name: &str, value: f64) -> Result<(), Error> {
+        self.observe_n(name, value, 1)
+    }
+
+    // Nothing is added when a count would overflow.
+    fn observe_n(&mut self, name: &str, value: f64, n: u64) -> Result<(), Error> {
+        let overflow = || Error::CountOverflow {
+            name: name.to_owned(),
+        };
+        let total = self.count.checked_add(n).ok_or_else(overflow)?;
+        if self
+            .buckets
+            .iter()
+            .any(|(bound, count)| value <= *bound && count.checked_add(n).is_none())
+        {
+            return Err(overflow());
+        }
+        for (bound, count) in self.buckets.iter_mut() {
+            if value <= *bound {
+                *count += n;
+            }
+        }
+        self.sum += value * n as f64;
+        self.count = total;
+        Ok(())
+    }
+
+    // Add the cumulative count of every bucket, nothing is added on error.
+    // The bounds must be the same as the series, with non-decreasing counts up to `count`.
+    fn observe_aggregated(
+        &mut self,
+        name: &str,
+        aggregated: &AggregatedObservations,
+    ) -> Result<(), Error> {
+        let counts = &aggregated.bucket_counts;
+        let valid = aggregated.bounds.len() == self.buckets.len()
+            && counts.len() == self.buckets.len()
+            && self
+                .buckets
+                .iter()
+                .zip(&aggregated.bounds)
+                .all(|((bound, _), other)| bound == other)
+            && counts.windows(2).all(|pair| pair[0] <= pair[1])
+            && counts.last().map_or(true, |last| *last <= aggregated.count);
+        if !valid {
+            return Err(Error::BucketMismatch {
+                name: name.to_owned(),
+                bounds: aggregated.bounds.to_owned(),
+            });
+        }
+        let overflow = || Error::CountOverflow {
+            name: name.to_owned(),
+        };
+        let total = self
+            .count
+            .checked_add(aggregated.count)
+            .ok_or_else(overflow)?;
+        // Bucket counts are at most `count`, they can't overflow if it doesn't
+        for ((_, count), added) in self.buckets.iter_mut().zip(counts) {
+            *count += added;
+        }
+        self.sum += aggregated.sum;
+        self.count = total;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SummaryValue {
+    pub sum: f64,
+    pub count: u64,
+}
+
+impl SummaryValue {
+    fn observe(&mut self, name: &str, value: f64) -> Result<(), Error> {
+        self.observe_n(name, value, 1)
+    }
+
+    fn observe_n(&mut self, name: &str, value: f64, n: u64) -> Result<(), Error> {
+        self.count = self
+            .count
+            .checked_add(n)
+            .ok_or_else(|| Error::CountOverflow {
+                name: name.to_owned(),
+            })?;
+        self.sum += value * n as f64;
+        Ok(())
+    }
+
+    fn observe_aggregated(
+        &mut self,
+        name: &str,
+        aggregated: &AggregatedObservations,
+    ) -> Result<(), Error> {
+        let overflow = || Error::CountOverflow {
+            name: name.to_owned(),
+        };
+        self.count = self
+            .count
+            .checked_add(aggregated.count)
+            .ok_or_else(overflow)?;
+        self.sum += aggregated.sum;
+        Ok(())
+    }
+}
+
+/// Current value of a single series.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Counter(f64),
+    Gauge(f64),
+    Histogram(HistogramValue),
+    Summary(SummaryValue),
+}
+
+/// All series sharing the same metric name.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Family {
+    pub kind: MetricType,
+    pub series: BTreeMap<Labels, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The operation has no `gauge`, `counter`, `histogram` or `summary` set.
+    MissingOperation { name: String },
+    /// The operation enum is `OPERATION_UNSPECIFIED` or a value unknown to this version.
+    InvalidOperation {
+        name: String,
+        kind: MetricType,
+        operation: i32,
+    },
+    /// The metric was already registered with another type.
+    TypeMismatch {
+        name: String,
+        expected: MetricType,
+        found: MetricType,
+    },
+    /// Counters can only go up.
+    NegativeCounterAdd { name: String, value: f64 },
+    /// Counters can only add finite values, `NaN` or infinity would stick to the series.
+    NonFiniteCounterAdd { name: String, value: f64 },
+    /// Counter totals can only move forward.
+    CounterDecrease {
+        name: String,
+        current: f64,
+        value: f64,
+    },
+    /// The aggregated histogram observations don't have the bucket bounds of the series,
+    /// or their cumulative bucket counts decrease or exceed the count.
+    BucketMismatch { name: String, bounds: Vec<f64> },
+    /// The `counts` of the observed values are set, but not one for each value.
+    CountMismatch {
+        name: String,
+        values: usize,
+        counts: usize,
+    },
+    /// The observation count of the series would overflow `u64`.
+    CountOverflow { name: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingOperation { name } => write!(f, "metric {name:?}: operation is not set"),
+            Error::InvalidOperation {
+                name,
+                kind,
+                operation,
+            } => {
+                write!(f, "metric {name:?}: invalid {kind} operation {operation}")
+            }
+            Error::TypeMismatch {
+                name,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "metric {name:?}: registered as {expected}, got a {found} operation"
+                )
+            }
+            Error::NegativeCounterAdd { name, value } => {
+                write!(f, "metric {name:?}: counter cannot decrease (add {value})")
+            }
+            Error::NonFiniteCounterAdd { name, value } => {
+                write!(f, "metric {name:?}: counter cannot add {value}")
+            }
+            Error::CounterDecrease {
+                name,
+                current,
+                value,
+            } => {
+                write!(
+                    f,
+                    "metric {name:?}: counter cannot decrease (total {current}, set to {value})"
+                )
+            }
+            Error::BucketMismatch { name, bounds } => {
+                write!(
+                    f,
+                    "metric {name:?}: aggregated observations with buckets {bounds:?} don't match the histogram buckets"
+                )
+            }
+            Error::CountMismatch {
+                name,
+                values,
+                counts,
+            } => {
+                write!(f, "metric {name:?}: {counts} counts for {values} values")
+            }
+            Error::CountOverflow { name } => {
+                write!(f, "metric {name:?}: observation count overflow")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// In-memory metric state built by applying `PrometheusOperations` in order,
+/// the same way the sink does before exposing `/metrics`.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{Counter, PrometheusOperations, Registry};
+/// let mut prom_ops: PrometheusOperations = Default::default();
+/// prom_ops.push(Counter::from("counter_name").inc());
+///
+/// let mut registry = Registry::new();
+/// registry.apply_all(&prom_ops).unwrap();
+/// assert_eq!(registry.to_text(), "# TYPE counter_name counter\ncounter_name 1\n");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Registry {
+    pub(crate) families: BTreeMap<String, Family>,
+    buckets: Vec<f64>,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self {
+            families: Default::default(),
+            buckets: DEFAULT_BUCKETS.to_vec(),
+        }
+    }
+}
+
+impl Registry {
+    /// Create new empty Registry using the default histogram buckets
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the bucket upper bounds used by histograms created after this call, sorted and deduplicated (`NaN` bounds are dropped)
+    #[inline]
+    pub fn with_buckets(mut self, buckets: Vec<f64>) -> Self {
+        self.buckets = sorted_bounds(buckets);
+        self
+    }
+
+    /// Metric families ordered by name
+    pub fn families(&self) -> impl Iterator<Item = (&String, &Family)> {
+        self.families.iter()
+    }
+
+    /// Current value of the series `name` with exactly the given `labels`
+    pub fn get(&self, name: &str, labels: &Labels) -> Option<&Value> {
+        self.families.get(name)?.series.get(labels)
+    }
+
+    /// Apply every operation of the batch in order, stopping at the first error.
+    ///
+    /// Then the series of the `snapshot` metrics that are not in the batch are removed.
+    pub fn apply_all(&mut self, operations: &PrometheusOperations) -> Result<(), Error> {
+        for operation in &operations.operations {
+            self.apply(operation)?;
+        }
+        for name in &operations.snapshot {
+            self.retain_snapshot(name, operations);
+        }
+        Ok(())
+    }
+
+    // Keep only the series of the metric `name` that are in the batch.
+    pub(crate) fn retain_snapshot(&mut self, name: &str, operations: &PrometheusOperations) {
+        let Some(family) = self.families.get_mut(name) else {
+            return;
+        };
+        let labels: HashSet<Labels> = operations
+            .operations
+            .iter()
+            .filter(|operation| operation.name == name)
+            .map(|operation| to_labels(&operation.labels))
+            .collect();
+        family.series.retain(|series, _| labels.contains(series));
+    }
+
+    /// Apply a single operation.
+    ///
+    /// `StartTimer` operations are accepted but ignored since no duration can be observed
+    /// outside of the sink process, and `SetToCurrentTime` uses the local clock.
+    pub fn apply(&mut self, operation: &PrometheusOperation) -> Result<(), Error> {
+        let name = &operation.name;
+        match &operation.operation {
+            None => Err(Error::MissingOperation {
+                name: name.to_owned(),
+            }),
+            Some(prometheus_operation::Operation::Counter(op)) => {
+                self.apply_counter(name, &operation.labels, op)
+            }
+            Some(prometheus_operation::Operation::Gauge(op)) => {
+                self.apply_gauge(name, &operation.labels, op)
+            }
+            Some(prometheus_operation::Operation::Histogram(op)) => {
+                self.apply_histogram(name, &operation.labels, op)
+            }
+            Some(prometheus_operation::Operation::Summary(op)) => {
+                self.apply_summary(name, &operation.labels, op)
+            }
+        }
+    }
+
+    fn apply_counter(
+        &mut self,
+        name: &str,
+        labels: &HashMap<String, String>,
+        op: &CounterOp,
+    ) -> Result<(), Error> {
+        let family = self.family(name, MetricType::Counter)?;
+        match counter_op::Operation::from_i32(op.operation) {
+            Some(counter_op::Operation::Inc) => *family.scalar(labels) += 1.0,
+            Some(counter_op::Operation::Add) if op.value < 0.0 => {
+                return Err(Error::NegativeCounterAdd {
+                    name: name.to_owned(),
+                    value: op.value,
+                })
+            }
+            Some(counter_op::Operation::Add) if !op.value.is_finite() => {
+                return Err(Error::NonFiniteCounterAdd {
+                    name: name.to_owned(),
+                    value: op.value,
+                })
+            }
+            Some(counter_op::Operation::Add) => *family.scalar(labels) += op.value,
+            Some(counter_op::Operation::SetTotal) => {
+                let current = match family.series.get(&to_labels(labels)) {
+                    Some(Value::Counter(current)) => *current,
+                    _ => 0.0,
+                };
+                if op.value.is_nan() || op.value < current {
+                    return Err(Error::CounterDecrease {
+                        name: name.to_owned(),
+                        current,
+                        value: op.value,
+                    });
+                }
+                *family.scalar(labels) = op.value;
+            }
+            Some(counter_op::Operation::Zero) => {
+                family.scalar(labels);
+            }
+            Some(counter_op::Operation::Remove) => family.remove(labels),
+            Some(counter_op::Operation::Reset) => family.series.clear(),
+            Some(counter_op::Operation::Unspecified) | None => {
+                return Err(family.invalid(name, op.operation))
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_gauge(
+        &mut self,
+        name: &str,
+        labels: &HashMap<String, String>,
+        op: &GaugeOp,
+    ) -> Result<(), Error> {
+        let family = self.family(name, MetricType::Gauge)?;
+        match gauge_op::Operation::from_i32(op.operation) {
+            Some(gauge_op::Operation::Inc) => *family.scalar(labels) += 1.0,
+            Some(gauge_op::Operation::Dec) => *family.scalar(labels) -= 1.0,
+            Some(gauge_op::Operation::Add) => *family.scalar(labels) += op.value,
+            Some(gauge_op::Operation::Sub) => *family.scalar(labels) -= op.value,
+            Some(gauge_op::Operation::Set) => *family.scalar(labels) = op.value,
+            Some(gauge_op::Operation::SetToCurrentTime) => *family.scalar(labels) = now(),
+            Some(gauge_op::Operation::Zero) => {
+                family.scalar(labels);
+            }
+            Some(gauge_op::Operation::Remove) => family.remove(labels),
+            Some(gauge_op::Operation::Reset) => family.series.clear(),
+            Some(gauge_op::Operation::Unspecified) | None => {
+                return Err(family.invalid(name, op.operation))
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_histogram(
+        &mut self,
+        name: &str,
+        labels: &HashMap<String, String>,
+        op: &HistogramOp,
+    ) -> Result<(), Error> {
+        let buckets = self.buckets.clone();
+        let family = self.family(name, MetricType::Histogram)?;
+        match histogram_op::Operation::from_i32(op.operation) {
+            Some(histogram_op::Operation::Observe) => {
+                family.histogram(labels, &buckets).observe(name, op.value)?
+            }
+            Some(histogram_op::Operation::Zero) => {
+                family.histogram(labels, &buckets);
+            }
+            Some(histogram_op::Operation::ObserveAggregated) => {
+                let Some(aggregated) = &op.aggregated else {
+                    return Err(family.invalid(name, op.operation));
+                };
+                family
+                    .histogram(labels, &buckets)
+                    .observe_aggregated(name, aggregated)?;
+            }
+            Some(histogram_op::Operation::ObserveMany) => {
+                let counts = observation_counts(name, &op.values, &op.counts)?;
+                // Observed on a copy so that an overflow leaves the series as it was
+                let histogram = family.histogram(labels, &buckets);
+                let mut observed = histogram.clone();
+                for (value, count) in op.values.iter().zip(counts) {
+                    observed.observe_n(name, *value, count)?;
+                }
+                *histogram = observed;
+            }
+            Some(histogram_op::Operation::StartTimer) => {}
+            Some(histogram_op::Operation::Remove) => family.remove(labels),
+            Some(histogram_op::Operation::Reset) => family.series.clear(),
+            Some(histogram_op::Operation::Unspecified) | None => {
+                return Err(family.invalid(name, op.operation))
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_summary(
+        &mut self,
+        name: &str,
+        labels: &HashMap<String, String>,
+        op: &SummaryOp,
+    ) -> Result<(), Error> {
+        let family = self.family(name, MetricType::Summary)?;
+        match summary_op::Operation::from_i32(op.operation) {
+            Some(summary_op::Operation::Observe) => {
+                family.summary(labels).observe(name, op.value)?
+            }
+            Some(summary_op::Operation::ObserveAggregated) => {
+                let Some(aggregated) = &op.aggregated else {
+                    return Err(family.invalid(name, op.operation));
+                };
+                family
+                    .summary(labels)
+                    .observe_aggregated(name, aggregated)?
+            }
+            Some(summary_op::Operation::ObserveMany) => {
+                let counts = observation_counts(name, &op.values, &op.counts)?;
+                let summary = family.summary(labels);
+                let mut observed = summary.clone();
+                for (value, count) in op.values.iter().zip(counts) {
+                    observed.observe_n(name, *value, count)?;
+                }
+                *summary = observed;
+            }
+            Some(summary_op::Operation::Zero) => {
+                family.summary(labels);
+            }
+            Some(summary_op::Operation::StartTimer) => {}
+            Some(summary_op::Operation::Remove) => family.remove(labels),
+            Some(summary_op::Operation::Reset) => family.series.clear(),
+            Some(summary_op::Operation::Unspecified) | None => {
+                return Err(family.invalid(name, op.operation))
+            }
+        }
+        Ok(())
+    }
+
+    fn family(&mut self, name: &str, kind: MetricType) -> Result<&mut Family, Error> {
+        let family = self
+            .families
+            .entry(name.to_owned())
+            .or_insert_with(|| Family {
+                kind,
+                series: Default::default(),
+            });
+        if family.kind != kind {
+            return Err(Error::TypeMismatch {
+                name: name.to_owned(),
+                expected: family.kind,
+                found: kind,
+            });
+        }
+        Ok(family)
+    }
+}
+
+impl Family {
+    // The family type is checked before any of the accessors below are used,
+    // so a series always holds a value of the family type.
+
+    fn scalar(&mut self, labels: &HashMap<String, String>) -> &mut f64 {
+        let kind = self.kind;
+        let value = self
+            .series
+            .entry(to_labels(labels))
+            .or_insert_with(|| match kind {
+                MetricType::Counter => Value::Counter(0.0),
+                _ => Value::Gauge(0.0),
+            });
+        match value {
+            Value::Counter(value) | Value::Gauge(value) => value,
+            _ => unreachable!("{kind} family holds no scalar values"),
+        }
+    }
+
+    fn histogram(
+        &mut self,
+        labels: &HashMap<String, String>,
+        buckets: &[f64],
+    ) -> &mut HistogramValue {
+        let value = self.series.entry(to_labels(labels));
+        match value.or_insert_with(|| Value::Histogram(HistogramValue::new(buckets))) {
+            Value::Histogram(histogram) => histogram,
+            _ => unreachable!("{} family holds no histogram values", self.kind),
+        }
+    }
+
+    fn summary(&mut self, labels: &HashMap<String, String>) -> &mut SummaryValue {
+        let value = self.series.entry(to_labels(labels));
+        match value.or_insert_with(|| Value::Summary(Default::default())) {
+            Value::Summary(summary) => summary,
+            _ => unreachable!("{} family holds no summary values", self.kind),
+        }
+    }
+
+    fn remove(&mut self, labels: &HashMap<String, String>) {
+        self.series.remove(&to_labels(labels));
+    }
+
+    fn invalid(&self, name: &str, operation: i32) -> Error {
+        Error::InvalidOperation {
+            name: name.to_owned(),
+            kind: self.kind,
+            operation,
+        }
+    }
+}
+
+pub(crate) fn to_labels(labels: &HashMap<String, String>) -> Labels {
+    labels
+        .iter()
+        .map(|(k, v)| (k.to_owned(), v.to_owned()))
+        .collect()
+}
+
+// Bucket upper bounds sorted and deduplicated, without `NaN`
+pub(crate) fn sorted_bounds(mut bounds: Vec<f64>) -> Vec<f64> {
+    bounds.retain(|bound| !bound.is_nan());
+    bounds.sort_by(f64::total_cmp);
+    bounds.dedup();
+    bounds
+}
+
+// Number of observations of each value, 1 each when `counts` is empty.
+fn observation_counts(name: &str, values: &[f64], counts: &[u64]) -> Result<Vec<u64>, Error> {
+    match counts.len() {
+        0 => Ok(vec![1; values.len()]),
+        len if len == values.len() => Ok(counts.to_vec()),
+        len => Err(Error::CountMismatch {
+            name: name.to_owned(),
+            values: values.len(),
+            counts: len,
+        }),
+    }
+}
+
+fn now() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs_f64())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{labels, Counter, Gauge, Histogram, Summary};
+
+    #[test]
+    fn test_registry() {
+        let labels = HashMap::from([("label1".to_string(), "value1".to_string())]);
+        let mut prom_ops: PrometheusOperations = Default::default();
+        let mut counter = Counter::from("counter_name").with(labels.clone());
+        let mut gauge = Gauge::from("gauge_name");
+        let mut histogram = Histogram::from("histogram_name");
+        let mut summary = Summary::from("summary_name");
+
+        prom_ops.push(counter.inc());
+        prom_ops.push(counter.add(2.5));
+        prom_ops.push(gauge.set(10.0));
+        prom_ops.push(gauge.sub(4.0));
+        prom_ops.push(gauge.dec());
+        prom_ops.push(histogram.observe(0.3));
+        prom_ops.push(histogram.observe(4.0));
+        prom_ops.push(summary.observe(1.5));
+        prom_ops.push(summary.start_timer());
+
+        let mut registry = Registry::new();
+        registry.apply_all(&prom_ops).unwrap();
+
+        assert_eq!(
+            registry.get("counter_name", &to_labels(&labels)),
+            Some(&Value::Counter(3.5))
+        );
+        assert_eq!(
+            registry.get("gauge_name", &Labels::new()),
+            Some(&Value::Gauge(5.0))
+        );
+        assert_eq!(
+            registry.get("summary_name", &Labels::new()),
+            Some(&Value::Summary(SummaryValue { sum: 1.5, count: 1 }))
+        );
+        match registry.get("histogram_name", &Labels::new()) {
+            Some(Value::Histogram(histogram)) => {
+                assert_eq!(histogram.count, 2);
+                assert_eq!(histogram.buckets[5], (0.25, 0));
+                assert_eq!(histogram.buckets[6], (0.5, 1));
+                assert_eq!(histogram.buckets[9], (5.0, 2));
+            }
+            value => panic!("unexpected value {value:?}"),
+        }
+
+        registry.apply(&counter.remove(labels).into()).unwrap();
+        registry.apply(&gauge.reset().into()).unwrap();
+        assert_eq!(
+            registry
+                .families()
+                .filter(|(_, family)| !family.series.is_empty())
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_registry_errors() {
+        let mut registry = Registry::new();
+        registry.apply(&Counter::from("a").inc().into()).unwrap();
+
+        assert_eq!(
+            registry.apply(&Gauge::from("a").inc().into()),
+            Err(Error::TypeMismatch {
+                name: "a".to_string(),
+                expected: MetricType::Counter,
+                found: MetricType::Gauge,
+            })
+        );
+        assert_eq!(
+            registry.apply(&Counter::from("a").add(-1.0).into()),
+            Err(Error::NegativeCounterAdd {
+                name: "a".to_string(),
+                value: -1.0
+            })
+        );
+        for value in [f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                registry.apply(&Counter::from("a").add(value).into()),
+                Err(Error::NonFiniteCounterAdd { .. })
+            ));
+        }
+        assert_eq!(
+            registry.get("a", &Labels::new()),
+            Some(&Value::Counter(1.0))
+        );
+        assert_eq!(
+            registry.apply(&PrometheusOperation {
+                name: "a".to_string(),
+                labels: Default::default(),
+                operation: None,
+            }),
+            Err(Error::MissingOperation {
+                name: "a".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_set_total() {
+        let labels = labels! {"token" => "USDT"};
+        let mut volume = Counter::from("volume_total").with(labels.clone());
+        let mut registry = Registry::new();
+        registry.apply(&volume.set_total(100.0).into()).unwrap();
+        registry.apply(&volume.add(5.0).into()).unwrap();
+        registry.apply(&volume.set_total(105.0).into()).unwrap();
+        assert_eq!(
+            registry.get("volume_total", &to_labels(&labels)),
+            Some(&Value::Counter(105.0))
+        );
+
+        assert_eq!(
+            registry.apply(&volume.set_total(50.0).into()),
+            Err(Error::CounterDecrease {
+                name: "volume_total".to_string(),
+                current: 105.0,
+                value: 50.0,
+            })
+        );
+        assert!(registry.apply(&volume.set_total(f64::NAN).into()).is_err());
+
+        // Intentional reset
+        registry
+            .apply(&volume.remove(labels.clone()).into())
+            .unwrap();
+        registry.apply(&volume.set_total(50.0).into()).unwrap();
+        assert_eq!(
+            registry.get("volume_total", &to_labels(&labels)),
+            Some(&Value::Counter(50.0))
+        );
+    }
+
+    #[test]
+    fn test_zero() {
+        let labels = labels! {"token" => "USDT"};
+        let mut transfers = Counter::from("transfers_total").with(labels.clone());
+        let mut registry = Registry::new();
+        registry.apply(&transfers.add(3.0).into()).unwrap();
+        registry
+            .apply(&transfers.zero(labels.clone()).into())
+            .unwrap();
+        registry
+            .apply(
+                &Counter::from("transfers_total")
+                    .zero(labels! {"token" => "DAI"})
+                    .into(),
+            )
+            .unwrap();
+        registry
+            .apply(&Gauge::from("balance").zero(labels.clone()).into())
+            .unwrap();
+        registry
+            .apply(&Summary::from("transfer_size").zero(labels).into())
+            .unwrap();
+        assert_eq!(
+            registry.to_text(),
+            "# TYPE balance gauge\nbalance{token=\"USDT\"} 0\n\
+             # TYPE transfer_size summary\ntransfer_size_sum{token=\"USDT\"} 0\ntransfer_size_count{token=\"USDT\"} 0\n\
+             # TYPE transfers_total counter\ntransfers_total{token=\"DAI\"} 0\ntransfers_total{token=\"USDT\"} 3\n"
+        );
+    }
+
+    #[test]
+    fn test_observe_many() {
+        let mut histogram = Histogram::from("gas_price");
+        let mut summary = Summary::from("gas_used");
+        let mut observed = Registry::new();
+        for (value, count) in [(1.0, 1), (0.2, 2), (20.0, 3)] {
+            for _ in 0..count {
+                observed.apply(&histogram.observe(value).into()).unwrap();
+                observed.apply(&summary.observe(value).into()).unwrap();
+            }
+        }
+
+        let mut registry = Registry::new();
+        registry
+            .apply(&histogram.observe_many(&[1.0, 0.2, 0.2]).into())
+            .unwrap();
+        registry
+            .apply(&histogram.observe_n(20.0, 3).into())
+            .unwrap();
+        registry
+            .apply(&summary.observe_many(&[1.0, 0.2]).into())
+            .unwrap();
+        registry.apply(&summary.observe_n(0.2, 1).into()).unwrap();
+        registry.apply(&summary.observe_n(20.0, 3).into()).unwrap();
+        assert_eq!(registry.to_text(), observed.to_text());
+
+        let mut op = HistogramOp::observe_many(vec![1.0, 2.0], vec![3]);
+        assert_eq!(
+            registry.apply(&PrometheusOperation {
+                name: "gas_price".to_string(),
+                labels: Default::default(),
+                operation: Some(prometheus_operation::Operation::Histogram(op.clone())),
+            }),
+            Err(Error::CountMismatch {
+                name: "gas_price".to_string(),
+                values: 2,
+                counts: 1,
+            })
+        );
+        op.counts.clear();
+        let operation = PrometheusOperation {
+            name: "gas_price".to_string(),
+            labels: Default::default(),
+            operation: Some(prometheus_operation::Operation::Histogram(op)),
+        };
+        registry.apply(&operation).unwrap();
+    }
+
+    #[test]
+    fn test_aggregated_bucket_mismatch() {
+        let aggregated = |bounds: &[f64], bucket_counts: &[u64], count: u64| {
+            Histogram::from("gas_price").observe_aggregated(AggregatedObservations {
+                bounds: bounds.to_vec(),
+                bucket_counts: bucket_counts.to_vec(),
+                sum: 1.0,
+                count,
+            })
+        };
+        // Sorted and deduplicated like the accumulator bounds
+        let mut registry = Registry::new().with_buckets(vec![2.0, f64::NAN, 1.0, 2.0]);
+        assert_eq!(registry, Registry::new().with_buckets(vec![1.0, 2.0]));
+        let cases: [(&[f64], &[u64], u64); 5] = [
+            // Decreasing cumulative counts
+            (&[1.0, 2.0], &[2, 1], 2),
+            // Bucket count above the count (`+Inf` bucket)
+            (&[1.0, 2.0], &[1, 3], 2),
+            // Bound unknown to the sink histogram
+            (&[0.5, 1.0, 2.0], &[0, 1, 1], 1),
+            // Missing bound
+            (&[2.0], &[1], 1),
+            // Bounds and counts of different lengths
+            (&[1.0, 2.0], &[1], 1),
+        ];
+        for (bounds, bucket_counts, count) in cases {
+            assert_eq!(
+                registry.apply(&aggregated(bounds, bucket_counts, count).into()),
+                Err(Error::BucketMismatch {
+                    name: "gas_price".to_string(),
+                    bounds: bounds.to_vec(),
+                })
+            );
+        }
+        registry
+            .apply(&aggregated(&[1.0, 2.0], &[1, 1], 2).into())
+            .unwrap();
+        assert_eq!(
+            registry.to_text(),
+            "# TYPE gas_price histogram\ngas_price_bucket{le=\"1\"} 1\ngas_price_bucket{le=\"2\"} 1\ngas_price_bucket{le=\"+Inf\"} 2\ngas_price_sum 1\ngas_price_count 2\n"
+        );
+    }
+
+    #[test]
+    fn test_count_overflow() {
+        let aggregated = |count: u64| AggregatedObservations {
+            bounds: vec![1.0],
+            bucket_counts: vec![count],
+            sum: 1.0,
+            count,
+        };
+        let mut histogram = Histogram::from("gas_price");
+        let mut summary = Summary::from("gas_used");
+        let mut registry = Registry::new().with_buckets(vec![1.0]);
+        registry
+            .apply(&histogram.observe_aggregated(aggregated(u64::MAX)).into())
+            .unwrap();
+        registry
+            .apply(&summary.observe_aggregated(aggregated(u64::MAX)).into())
+            .unwrap();
+        let text = registry.to_text();
+
+        assert_eq!(
+            registry.apply(&histogram.observe_aggregated(aggregated(1)).into()),
+            Err(Error::CountOverflow {
+                name: "gas_price".to_string()
+            })
+        );
+        assert_eq!(
+            registry.apply(&summary.observe_aggregated(aggregated(1)).into()),
+            Err(Error::CountOverflow {
+                name: "gas_used".to_string()
+            })
+        );
+        assert_eq!(
+            registry.apply(&histogram.observe(0.5).into()),
+            Err(Error::CountOverflow {
+                name: "gas_price".to_string()
+            })
+        );
+        assert_eq!(
+            registry.apply(&summary.observe_n(0.5, 2).into()),
+            Err(Error::CountOverflow {
+                name: "gas_used".to_string()
+            })
+        );
+
+        assert_eq!(registry.to_text(), text);
+
+        // Counts of several values overflowing together
+        let mut gas_limit = Histogram::from("gas_limit");
+        registry
+            .apply(&gas_limit.observe_n(2.0, u64::MAX - 1).into())
+            .unwrap();
+        let text = registry.to_text();
+        assert_eq!(
+            registry.apply(&gas_limit.observe_many(&[3.0, 0.5]).into()),
+            Err(Error::CountOverflow {
+                name: "gas_limit".to_string()
+            })
+        );
+        // Nothing was added
+        assert_eq!(registry.to_text(), text);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let top_pool = |pool: &str, volume: f64| {
+            let labels = HashMap::from([("pool".to_string(), pool.to_string())]);
+            Gauge::from("top_pools_volume").with(labels).set(volume)
+        };
+        let mut registry = Registry::new();
+        let mut prom_ops = PrometheusOperations::default().snapshot("top_pools_volume");
+        prom_ops.push(top_pool("0xa", 10.0));
+        prom_ops.push(top_pool("0xb", 5.0));
+        prom_ops.push(Gauge::from("pools").set(2.0));
+        registry.apply_all(&prom_ops).unwrap();
+
+        let mut prom_ops = PrometheusOperations::default().snapshot("top_pools_volume");
+        prom_ops.push(top_pool("0xc", 20.0));
+        prom_ops.push(top_pool("0xa", 12.0));
+        registry.apply_all(&prom_ops).unwrap();
+        assert_eq!(
+            registry.to_text(),
+            "# TYPE pools gauge\npools 2\n# TYPE top_pools_volume gauge\ntop_pools_volume{pool=\"0xa\"} 12\ntop_pools_volume{pool=\"0xc\"} 20\n"
+        );
+
+        // An empty snapshot removes every series
+        registry
+            .apply_all(&PrometheusOperations::default().snapshot("top_pools_volume"))
+            .unwrap();
+        assert_eq!(registry.to_text(), "# TYPE pools gauge\npools 2\n");
+    }
+}
@@ -0,0 +1,649 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{
+    cardinality::is_reset, registry::to_labels, AggregatedObservations, Counter, Error, Family,
+    FinalityBuffer, Gauge, Histogram, Labels, MetricType, PrometheusOperation,
+    PrometheusOperations, Registry, Summary, Value,
+};
+
+/// Operations emitted for a single block.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct BlockOperations {
+    pub number: u64,
+    pub id: String,
+    pub operations: PrometheusOperations,
+}
+
+// State of a family or series before an operation of the block was applied.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Undo {
+    Family {
+        name: String,
+        previous: Option<Family>,
+    },
+    Series {
+        name: String,
+        labels: Labels,
+        previous: Option<Value>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Journal {
+    number: u64,
+    id: String,
+    operations: PrometheusOperations,
+    undo: Vec<Undo>,
+}
+
+/// `final_only` batch dropped by `ReversibleRegistry::finalize` because it couldn't be applied.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RejectedBatch {
+    pub number: u64,
+    pub operations: PrometheusOperations,
+    pub error: Error,
+}
+
+/// Registry keeping an undo journal of the blocks that are not final yet,
+/// so metrics can be rolled back to the last valid block when a fork is detected.
+///
+/// Every operation records the previous value of the series it touches (or of the whole family for `Reset`),
+/// which makes the rollback exact for all metric types, counters included.
+///
+/// `final_only` batches are held in a `FinalityBuffer` and applied by `finalize` once their block is final,
+/// underneath the blocks that are not final yet: those are rolled back and replayed on top of the final batches,
+/// so undoing them keeps the final changes, and their newer values still win over older final ones.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{BlockOperations, Counter, PrometheusOperations, ReversibleRegistry};
+/// let mut registry = ReversibleRegistry::default();
+/// let mut transfers = Counter::from("transfers_total");
+/// for number in [1, 2] {
+///     let mut operations = PrometheusOperations::default();
+///     operations.push(transfers.inc());
+///     registry.apply_block(&BlockOperations { number, id: format!("#{number}"), operations }).unwrap();
+/// }
+///
+/// // Block #2 was orphaned
+/// registry.undo(1);
+/// assert_eq!(registry.registry().to_text(), "# TYPE transfers_total counter\ntransfers_total 1\n");
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ReversibleRegistry {
+    registry: Registry,
+    blocks: VecDeque<Journal>,
+    pending: FinalityBuffer,
+}
+
+impl ReversibleRegistry {
+    /// Create new ReversibleRegistry on top of `registry`, considered final
+    #[inline]
+    #[must_use]
+    pub fn new(registry: Registry) -> Self {
+        Self {
+            registry,
+            blocks: Default::default(),
+            pending: Default::default(),
+        }
+    }
+
+    /// Current state, including the blocks that are not final yet
+    #[inline]
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    /// Number and id of the blocks that can still be rolled back, oldest first
+    pub fn blocks(&self) -> impl Iterator<Item = (u64, &str)> {
+        self.blocks
+            .iter()
+            .map(|journal| (journal.number, journal.id.as_str()))
+    }
+
+    /// Apply the operations of a block, or buffer them until `finalize` if they are `final_only`.
+    ///
+    /// The block is applied entirely or not at all: on error, the operations already applied are rolled back.
+    pub fn apply_block(&mut self, block: &BlockOperations) -> Result<(), Error> {
+        if block.operations.final_only {
+            self.pending.push(block.number, block.operations.clone());
+            return Ok(());
+        }
+        let undo = apply(&mut self.registry, &block.operations)?;
+        self.blocks.push_back(Journal {
+            number: block.number,
+            id: block.id.to_owned(),
+            operations: block.operations.clone(),
+            undo,
+        });
+        Ok(())
+    }
+
+    /// Roll back the blocks after `last_valid_block` (as given by the undo signal of the fork).
+    ///
+    /// Returns the operations reverting the same changes, for sinks forwarding operations instead of using the registry:
+    /// gauges are set back to their previous value, counters are removed and re-added with their previous value,
+    /// histograms and summaries are removed and rebuilt with `ObserveAggregated` from their previous buckets, sum and count,
+    /// and series created by the orphaned blocks are removed.
+    pub fn undo(&mut self, last_valid_block: u64) -> Vec<PrometheusOperation> {
+        self.pending.undo(last_valid_block);
+        let mut operations = vec![];
+        while let Some(journal) = self.blocks.back() {
+            if journal.number <= last_valid_block {
+                break;
+            }
+            let journal = self.blocks.pop_back().expect("last block");
+            for undo in journal.undo.into_iter().rev() {
+                restore(&mut self.registry, undo, &mut operations);
+            }
+        }
+        operations
+    }
+
+    /// Drop the undo journal of the blocks up to `final_block`, they can't be rolled back anymore,
+    /// and apply the `final_only` batches of those blocks in block order, underneath the blocks that are not final yet.
+    ///
+    /// Each batch is applied entirely or not at all. A batch failing to apply, or making a block that is not final yet
+    /// fail to replay on top of it, is dropped and returned since it can't be fixed by a fork; the later batches are still applied.
+    #[must_use = "rejected batches should be reported"]
+    pub fn finalize(&mut self, final_block: u64) -> Vec<RejectedBatch> {
+        while self
+            .blocks
+            .front()
+            .map_or(false, |journal| journal.number <= final_block)
+        {
+            self.blocks.pop_front();
+        }
+        let mut rejected = vec![];
+        if self.pending.is_final(final_block) {
+            let blocks = self.rewind();
+            while let Some((number, operations)) = self.pending.pop_final(final_block) {
+                let result = apply(&mut self.registry, &operations).and_then(|undo| {
+                    // Checked by replaying the blocks on top, which roll themselves back on error
+                    let replayed = self.replay(&blocks);
+                    if replayed.is_err() {
+                        restore_all(&mut self.registry, undo);
+                    }
+                    replayed
+                });
+                match result {
+                    Ok(()) => {
+                        self.rewind();
+                    }
+                    Err(error) => rejected.push(RejectedBatch {
+                        number,
+                        operations,
+                        error,
+                    }),
+                }
+            }
+            // Replayed on the state they were applied on, or already replayed once on top of the final batches
+            let replayed = self.replay(&blocks);
+            debug_assert!(replayed.is_ok(), "blocks failed to replay: {replayed:?}");
+        }
+        rejected
+    }
+
+    // Roll back every block that is not final yet, returning them oldest first
+    fn rewind(&mut self) -> Vec<Journal> {
+        let mut blocks = Vec::with_capacity(self.blocks.len());
+        while let Some(mut journal) = self.blocks.pop_back() {
+            restore_all(&mut self.registry, std::mem::take(&mut journal.undo));
+            blocks.push(journal);
+        }
+        blocks.reverse();
+        blocks
+    }
+
+    // Apply the rewound blocks again, all of them or none
+    fn replay(&mut self, blocks: &[Journal]) -> Result<(), Error> {
+        for (replayed, block) in blocks.iter().enumerate() {
+            match apply(&mut self.registry, &block.operations) {
+                Ok(undo) => self.blocks.push_back(Journal {
+                    undo,
+                    ..block.clone()
+                }),
+                Err(error) => {
+                    for _ in 0..replayed {
+                        let journal = self.blocks.pop_back().expect("replayed block");
+                        restore_all(&mut self.registry, journal.undo);
+                    }
+                    return Err(error);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn restore_all(registry: &mut Registry, undo: Vec<Undo>) {
+    for undo in undo.into_iter().rev() {
+        restore(registry, undo, &mut vec![]);
+    }
+}
+
+// Apply a batch entirely or not at all, returning the undo journal of its changes
+pub(crate) fn apply(
+    registry: &mut Registry,
+    operations: &PrometheusOperations,
+) -> Result<Vec<Undo>, Error> {
+    let mut undo = Vec::with_capacity(operations.operations.len());
+    for operation in &operations.operations {
+        undo.push(record(registry, operation));
+        if let Err(err) = registry.apply(operation) {
+            restore_all(registry, undo);
+            return Err(err);
+        }
+    }
+    for name in &operations.snapshot {
+        if let Some(family) = registry.families.get(name) {
+            undo.push(Undo::Family {
+                name: name.to_owned(),
+                previous: Some(family.clone()),
+            });
+            registry.retain_snapshot(name, operations);
+        }
+    }
+    Ok(undo)
+}
+
+fn record(registry: &Registry, operation: &PrometheusOperation) -> Undo {
+    let name = operation.name.to_owned();
+    match registry.families.get(&name) {
+        None => Undo::Family {
+            name,
+            previous: None,
+        },
+        Some(family) if is_reset(operation) => Undo::Family {
+            name,
+            previous: Some(family.clone()),
+        },
+        Some(family) => {
+            let labels = to_labels(&operation.labels);
+            Undo::Series {
+                name,
+                previous: family.series.get(&labels).cloned(),
+                labels,
+            }
+        }
+    }
+}
+
+fn restore(registry: &mut Registry, undo: Undo, operations: &mut Vec<PrometheusOperation>) {
+    let families = &mut registry.families;
+    match undo {
+        Undo::Family {
+            name,
+            previous: None,
+        } => {
+            if let Some(family) = families.remove(&name) {
+                operations.push(reset(family.kind, &name));
+            }
+        }
+        Undo::Family {
+            name,
+            previous: Some(previous),
+        } => {
+            operations.push(reset(previous.kind, &name));
+            for (labels, value) in &previous.series {
+                restore_series(&name, labels, value, operations);
+            }
+            families.insert(name, previous);
+        }
+        Undo::Series {
+            name,
+            labels,
+            previous,
+        } => {
+            let Some(family) = families.get_mut(&name) else {
+                return;
+            };
+            match &previous {
+                Some(value) => family.series.insert(labels.to_owned(), value.clone()),
+                None => family.series.remove(&labels),
+            };
+            match previous {
+                Some(value) => restore_series(&name, &labels, &value, operations),
+                None => operations.push(remove(family.kind, &name, &labels)),
+            }
+        }
+    }
+}
+
+fn restore_series(
+    name: &str,
+    labels: &Labels,
+    previous: &Value,
+    operations: &mut Vec<PrometheusOperation>,
+) {
+    let labels: HashMap<String, String> = labels.clone().into_iter().collect();
+    match previous {
+        Value::Gauge(value) => operations.push(Gauge::from(name).with(labels).set(*value).into()),
+        Value::Counter(value) => {
+            let mut counter = Counter::from(name).with(labels.clone());
+            operations.push(counter.remove(labels).into());
+            operations.push(counter.add(*value).into());
+        }
+        Value::Histogram(value) => {
+            let mut histogram = Histogram::from(name).with(labels.clone());
+            operations.push(histogram.remove(labels).into());
+            operations.push(
+                histogram
+                    .observe_aggregated(AggregatedObservations {
+                        bounds: value.buckets.iter().map(|(bound, _)| *bound).collect(),
+                        bucket_counts: value.buckets.iter().map(|(_, count)| *count).collect(),
+                        sum: value.sum,
+                        count: value.count,
+                    })
+                    .into(),
+            );
+        }
+        Value::Summary(value) => {
+            let mut summary = Summary::from(name).with(labels.clone());
+            operations.push(summary.remove(labels).into());
+            operations.push(
+                summary
+                    .observe_aggregated(AggregatedObservations {
+                        sum: value.sum,
+                        count: value.count,
+                        ..Default::default()
+                    })
+                    .into(),
+            );
+        }
+    }
+}
+
+pub(crate) fn remove(kind: MetricType, name: &str, labels: &Labels) -> PrometheusOperation {
+    let labels: HashMap<String, String> = labels.clone().into_iter().collect();
+    match kind {
+        MetricType::Counter => Counter::from(name).remove(labels).into(),
+        MetricType::Gauge => Gauge::from(name).remove(labels).into(),
+        MetricType::Histogram => Histogram::from(name).remove(labels).into(),
+        MetricType::Summary => Summary::from(name).remove(labels).into(),
+    }
+}
+
+fn reset(kind: MetricType, name: &str) -> PrometheusOperation {
+    match kind {
+        MetricType::Counter => Counter::from(name).reset().into(),
+        MetricType::Gauge => Gauge::from(name).reset().into(),
+        MetricType::Histogram => Histogram::from(name).reset().into(),
+        MetricType::Summary => Summary::from(name).reset().into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{labels, SeriesOperation};
+
+    fn block(number: u64, operations: Vec<SeriesOperation>) -> BlockOperations {
+        let mut prom_ops = PrometheusOperations::default();
+        prom_ops.extend(operations);
+        BlockOperations {
+            number,
+            id: format!("#{number}"),
+            operations: prom_ops,
+        }
+    }
+
+    #[test]
+    fn test_undo() {
+        let mut transfers = Counter::from("transfers_total").with(labels! {"token" => "USDT"});
+        let mut balance = Gauge::from("balance").with(labels! {"holder" => "0xa"});
+        let mut other = Gauge::from("balance").with(labels! {"holder" => "0xb"});
+        let mut supply = Gauge::from("supply");
+        let mut size = Histogram::from("transfer_size");
+
+        let mut registry = ReversibleRegistry::default();
+        let block1 = block(
+            1,
+            vec![transfers.inc(), balance.set(10.0), supply.set(100.0)],
+        );
+        registry.apply_block(&block1).unwrap();
+        let final_state = registry.registry().clone();
+
+        let block2 = block(
+            2,
+            vec![
+                transfers.add(2.0),
+                balance.add(5.0),
+                other.set(1.0),
+                supply.reset(),
+                supply.set(50.0),
+            ],
+        );
+        let block3 = block(3, vec![balance.set_to_current_time(), size.observe(1.0)]);
+        registry.apply_block(&block2).unwrap();
+        registry.apply_block(&block3).unwrap();
+        let forked_state = registry.registry().clone();
+        assert_eq!(
+            registry.blocks().collect::<Vec<_>>(),
+            vec![(1, "#1"), (2, "#2"), (3, "#3")]
+        );
+
+        let operations = registry.undo(1);
+        assert_eq!(registry.registry(), &final_state);
+        assert_eq!(registry.blocks().count(), 1);
+
+        // The inverse operations bring a plain registry to the same state
+        let mut forwarded = forked_state;
+        forwarded
+            .apply_all(&PrometheusOperations {
+                operations,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(forwarded.to_text(), final_state.to_text());
+    }
+
+    #[test]
+    fn test_undo_observations() {
+        let mut size = Histogram::from("transfer_size").with(labels! {"token" => "USDT"});
+        let mut gas = Summary::from("gas_used");
+        let block1 = block(
+            1,
+            vec![size.observe(0.2), size.observe(3.0), gas.observe(21_000.0)],
+        );
+        let block2 = block(
+            2,
+            vec![size.reset(), size.observe(1.0), gas.observe(50_000.0)],
+        );
+        let mut registry = ReversibleRegistry::default();
+        registry.apply_block(&block1).unwrap();
+        let final_state = registry.registry().clone();
+        registry.apply_block(&block2).unwrap();
+        let operations = registry.undo(1);
+        assert_eq!(registry.registry(), &final_state);
+
+        // Replayed in a fresh registry, the inverse operations rebuild the previous observations
+        let mut forwarded = Registry::new();
+        forwarded.apply_all(&block1.operations).unwrap();
+        forwarded.apply_all(&block2.operations).unwrap();
+        forwarded
+            .apply_all(&PrometheusOperations {
+                operations,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(&forwarded, registry.registry());
+    }
+
+    #[test]
+    fn test_apply_block_error() {
+        let mut registry = ReversibleRegistry::default();
+        registry
+            .apply_block(&block(1, vec![Counter::from("transfers_total").inc()]))
+            .unwrap();
+        let state = registry.clone();
+
+        let invalid = block(
+            2,
+            vec![
+                Counter::from("transfers_total").inc(),
+                Gauge::from("balance").set(1.0),
+                Gauge::from("transfers_total").set(1.0),
+            ],
+        );
+        assert!(registry.apply_block(&invalid).is_err());
+        assert_eq!(registry, state);
+    }
+
+    #[test]
+    fn test_finalize() {
+        let mut registry = ReversibleRegistry::default();
+        let mut transfers = Counter::from("transfers_total");
+        for number in 1..=3 {
+            registry
+                .apply_block(&block(number, vec![transfers.inc()]))
+                .unwrap();
+        }
+        assert!(registry.finalize(2).is_empty());
+        assert_eq!(registry.blocks().collect::<Vec<_>>(), vec![(3, "#3")]);
+
+        // Final blocks are kept
+        registry.undo(0);
+        assert_eq!(
+            registry.registry().get("transfers_total", &Labels::new()),
+            Some(&Value::Counter(2.0))
+        );
+    }
+
+    #[test]
+    fn test_final_only() {
+        let mut volume = Counter::from("settlement_volume_total");
+        let mut registry = ReversibleRegistry::default();
+        for number in 1..=3 {
+            let mut block = block(number, vec![volume.add(10.0)]);
+            block.operations.final_only = true;
+            registry.apply_block(&block).unwrap();
+        }
+        assert_eq!(registry.registry(), &Registry::new());
+
+        registry.undo(2);
+        assert!(registry.finalize(2).is_empty());
+        assert_eq!(
+            registry
+                .registry()
+                .get("settlement_volume_total", &Labels::new()),
+            Some(&Value::Counter(20.0))
+        );
+        assert!(registry.finalize(3).is_empty());
+        assert_eq!(
+            registry
+                .registry()
+                .get("settlement_volume_total", &Labels::new()),
+            Some(&Value::Counter(20.0))
+        );
+    }
+
+    #[test]
+    fn test_finalize_error() {
+        let mut registry = ReversibleRegistry::default();
+        let mut invalid = block(
+            1,
+            vec![Counter::from("x").add(10.0), Gauge::from("x").set(1.0)],
+        );
+        invalid.operations.final_only = true;
+        let mut valid = block(2, vec![Counter::from("y").inc()]);
+        valid.operations.final_only = true;
+        registry.apply_block(&invalid).unwrap();
+        registry.apply_block(&valid).unwrap();
+
+        // The invalid batch is rolled back and dropped, the next one is applied
+        let rejected = registry.finalize(2);
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].number, 1);
+        assert_eq!(rejected[0].operations, invalid.operations);
+        assert!(matches!(rejected[0].error, Error::TypeMismatch { .. }));
+        assert_eq!(registry.registry().to_text(), "# TYPE y counter\ny 1\n");
+        assert!(registry.pending.is_empty());
+        assert!(registry.finalize(3).is_empty());
+    }
+
+    #[test]
+    fn test_undo_across_finalize() {
+        let mut registry = ReversibleRegistry::default();
+        let mut settled = block(5, vec![Counter::from("x").add(10.0)]);
+        settled.operations.final_only = true;
+        registry.apply_block(&settled).unwrap();
+        registry
+            .apply_block(&block(10, vec![Counter::from("x").inc()]))
+            .unwrap();
+
+        // The final batch goes underneath block 10, undoing that block keeps it
+        assert!(registry.finalize(5).is_empty());
+        assert_eq!(registry.registry().to_text(), "# TYPE x counter\nx 11\n");
+        registry.undo(9);
+        assert_eq!(registry.registry().to_text(), "# TYPE x counter\nx 10\n");
+    }
+
+    #[test]
+    fn test_finalize_precedence() {
+        let mut registry = ReversibleRegistry::default();
+        let mut settled = block(6, vec![Gauge::from("price").set(1.0)]);
+        settled.operations.final_only = true;
+        registry.apply_block(&settled).unwrap();
+        registry
+            .apply_block(&block(7, vec![Gauge::from("price").set(2.0)]))
+            .unwrap();
+
+        // The newer block still wins over the older final batch
+        assert!(registry.finalize(6).is_empty());
+        assert_eq!(
+            registry.registry().to_text(),
+            "# TYPE price gauge\nprice 2\n"
+        );
+        registry.undo(6);
+        assert_eq!(
+            registry.registry().to_text(),
+            "# TYPE price gauge\nprice 1\n"
+        );
+    }
+
+    #[test]
+    fn test_finalize_replay_error() {
+        let mut registry = ReversibleRegistry::default();
+        let mut conflicting = block(1, vec![Gauge::from("x").set(1.0)]);
+        conflicting.operations.final_only = true;
+        registry.apply_block(&conflicting).unwrap();
+        registry
+            .apply_block(&block(2, vec![Counter::from("x").inc()]))
+            .unwrap();
+
+        // Block 2 can't replay on top of the final batch, which is dropped instead
+        let rejected = registry.finalize(1);
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].number, 1);
+        assert!(matches!(rejected[0].error, Error::TypeMismatch { .. }));
+        assert_eq!(registry.registry().to_text(), "# TYPE x counter\nx 1\n");
+        assert_eq!(registry.blocks().collect::<Vec<_>>(), vec![(2, "#2")]);
+        registry.undo(1);
+        assert_eq!(registry.registry(), &Registry::new());
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let top_pool = |pool: &str| Gauge::from("top_pools").with(labels! {"pool" => pool});
+        let mut registry = ReversibleRegistry::default();
+        registry
+            .apply_block(&block(
+                1,
+                vec![top_pool("0xa").set(10.0), top_pool("0xb").set(5.0)],
+            ))
+            .unwrap();
+        let final_state = registry.registry().clone();
+
+        let mut block2 = block(2, vec![top_pool("0xc").set(20.0)]);
+        block2.operations = block2.operations.snapshot("top_pools");
+        registry.apply_block(&block2).unwrap();
+        assert_eq!(
+            registry.registry().to_text(),
+            "# TYPE top_pools gauge\ntop_pools{pool=\"0xc\"} 20\n"
+        );
+
+        registry.undo(1);
+        assert_eq!(registry.registry(), &final_state);
+    }
+}
@@ -0,0 +1,389 @@
+use std::fmt;
+
+use substreams::scalar::{BigDecimal, BigInt};
+
+use crate::{Counter, Gauge, Histogram, SeriesOperation, Summary};
+
+/// How an on-chain amount was converted to `f64`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Precision {
+    /// The `f64` is exactly the amount.
+    Exact,
+    /// The amount was rounded to the nearest `f64` (ex: more than 15-17 significant digits).
+    Rounded,
+    /// The amount is out of the `f64` range, the value is `+Inf` or `-Inf`.
+    Overflow,
+}
+
+/// On-chain amount converted to `f64`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Scaled {
+    pub value: f64,
+    pub precision: Precision,
+}
+
+impl Scaled {
+    #[inline]
+    pub fn is_exact(&self) -> bool {
+        self.precision == Precision::Exact
+    }
+
+    // Value of the builder overloads, which refuse amounts out of the `f64` range
+    fn finite(self) -> Result<f64, OverflowError> {
+        match self.precision {
+            Precision::Overflow => Err(OverflowError { value: self.value }),
+            _ => Ok(self.value),
+        }
+    }
+}
+
+/// The amount passed to a builder is out of the `f64` range, `value` is `+Inf` or `-Inf`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OverflowError {
+    pub value: f64,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "amount out of the f64 range ({})", self.value)
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+/// Convert a raw token amount with `decimals` to the nearest `f64` (`amount / 10^decimals`).
+///
+/// ### Example
+/// ```
+/// use substreams::scalar::BigInt;
+/// use substreams_sink_prometheus::{scale_big_int, Precision};
+/// let amount = BigInt::try_from("1500000000000000000".to_string()).unwrap();
+/// let scaled = scale_big_int(&amount, 18);
+/// assert_eq!(scaled.value, 1.5);
+/// assert_eq!(scaled.precision, Precision::Exact);
+/// ```
+pub fn scale_big_int(amount: &BigInt, decimals: u32) -> Scaled {
+    let digits: num_bigint::BigInt = amount.clone().into();
+    to_f64(&digits, decimals.into())
+}
+
+/// Convert a decimal to the nearest `f64`.
+pub fn big_decimal_to_f64(value: &BigDecimal) -> Scaled {
+    let (digits, scale) = value.as_bigint_and_exponent();
+    to_f64(&digits, scale)
+}
+
+impl Counter {
+    /// Adds a raw token amount with `decimals` to the Counter, see `scale_big_int`.
+    ///
+    /// The builders taking an amount return an `OverflowError` for amounts out of the `f64` range,
+    /// rounded amounts are accepted.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams::scalar::BigInt;
+    /// use substreams_sink_prometheus::{Counter, PrometheusOperations};
+    /// let mut prom_ops: PrometheusOperations = Default::default();
+    /// let amount = BigInt::from(1_500_000u64);
+    /// prom_ops.push(Counter::from("volume_usdt_total").add_scaled(&amount, 6).unwrap());
+    /// ```
+    #[inline]
+    pub fn add_scaled(
+        &mut self,
+        amount: &BigInt,
+        decimals: u32,
+    ) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.add(scale_big_int(amount, decimals).finite()?))
+    }
+
+    /// Adds a decimal to the Counter, see `big_decimal_to_f64`.
+    #[inline]
+    pub fn add_decimal(&mut self, value: &BigDecimal) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.add(big_decimal_to_f64(value).finite()?))
+    }
+
+    /// Sets the Counter to a raw token total with `decimals`, see `scale_big_int`.
+    #[inline]
+    pub fn set_total_scaled(
+        &mut self,
+        total: &BigInt,
+        decimals: u32,
+    ) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.set_total(scale_big_int(total, decimals).finite()?))
+    }
+
+    /// Sets the Counter to a decimal total, see `big_decimal_to_f64`.
+    #[inline]
+    pub fn set_total_decimal(
+        &mut self,
+        total: &BigDecimal,
+    ) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.set_total(big_decimal_to_f64(total).finite()?))
+    }
+}
+
+impl Gauge {
+    /// Sets the Gauge to a raw token amount with `decimals`, see `scale_big_int`.
+    #[inline]
+    pub fn set_scaled(
+        &mut self,
+        amount: &BigInt,
+        decimals: u32,
+    ) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.set(scale_big_int(amount, decimals).finite()?))
+    }
+
+    /// Sets the Gauge to a decimal, see `big_decimal_to_f64`.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams::scalar::BigDecimal;
+    /// use substreams_sink_prometheus::{Gauge, PrometheusOperations};
+    /// let mut prom_ops: PrometheusOperations = Default::default();
+    /// let price = BigDecimal::try_from("1834.25").unwrap();
+    /// prom_ops.push(Gauge::from("eth_price_usd").set_decimal(&price).unwrap());
+    /// ```
+    #[inline]
+    pub fn set_decimal(&mut self, value: &BigDecimal) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.set(big_decimal_to_f64(value).finite()?))
+    }
+
+    /// Adds a raw token amount with `decimals` to the Gauge, see `scale_big_int`.
+    #[inline]
+    pub fn add_scaled(
+        &mut self,
+        amount: &BigInt,
+        decimals: u32,
+    ) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.add(scale_big_int(amount, decimals).finite()?))
+    }
+
+    /// Adds a decimal to the Gauge, see `big_decimal_to_f64`.
+    #[inline]
+    pub fn add_decimal(&mut self, value: &BigDecimal) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.add(big_decimal_to_f64(value).finite()?))
+    }
+
+    /// Subtracts a raw token amount with `decimals` from the Gauge, see `scale_big_int`.
+    #[inline]
+    pub fn sub_scaled(
+        &mut self,
+        amount: &BigInt,
+        decimals: u32,
+    ) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.sub(scale_big_int(amount, decimals).finite()?))
+    }
+
+    /// Subtracts a decimal from the Gauge, see `big_decimal_to_f64`.
+    #[inline]
+    pub fn sub_decimal(&mut self, value: &BigDecimal) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.sub(big_decimal_to_f64(value).finite()?))
+    }
+}
+
+impl Histogram {
+    /// Observe a raw token amount with `decimals`, see `scale_big_int`.
+    #[inline]
+    pub fn observe_scaled(
+        &mut self,
+        amount: &BigInt,
+        decimals: u32,
+    ) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.observe(scale_big_int(amount, decimals).finite()?))
+    }
+
+    /// Observe a decimal, see `big_decimal_to_f64`.
+    #[inline]
+    pub fn observe_decimal(
+        &mut self,
+        value: &BigDecimal,
+    ) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.observe(big_decimal_to_f64(value).finite()?))
+    }
+}
+
+impl Summary {
+    /// Observe a raw token amount with `decimals`, see `scale_big_int`.
+    #[inline]
+    pub fn observe_scaled(
+        &mut self,
+        amount: &BigInt,
+        decimals: u32,
+    ) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.observe(scale_big_int(amount, decimals).finite()?))
+    }
+
+    /// Observe a decimal, see `big_decimal_to_f64`.
+    #[inline]
+    pub fn observe_decimal(
+        &mut self,
+        value: &BigDecimal,
+    ) -> Result<SeriesOperation, OverflowError> {
+        Ok(self.observe(big_decimal_to_f64(value).finite()?))
+    }
+}
+
+// `digits * 10^-scale`, correctly rounded by the float parser.
+fn to_f64(digits: &num_bigint::BigInt, scale: i64) -> Scaled {
+    let value: f64 = format!("{digits}e{}", -i128::from(scale))
+        .parse()
+        .expect("valid float literal");
+    let precision = if value.is_infinite() {
+        Precision::Overflow
+    } else if is_exact(value, digits, scale) {
+        Precision::Exact
+    } else {
+        Precision::Rounded
+    };
+    Scaled { value, precision }
+}
+
+// Compare `mantissa * 2^exponent` with `digits * 10^-scale` as integers.
+// The value is finite, so both sides have about as many digits as `digits`.
+fn is_exact(value: f64, digits: &num_bigint::BigInt, scale: i64) -> bool {
+    if value == 0.0 {
+        return digits.bits() == 0;
+    }
+    let bits = value.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i64;
+    let fraction = bits & 0xf_ffff_ffff_ffff;
+    let (mantissa, exponent) = match biased {
+        0 => (fraction, -1074),
+        _ => (fraction | 0x10_0000_0000_0000, biased - 1075),
+    };
+    let mut lhs = num_bigint::BigInt::from(mantissa);
+    if value < 0.0 {
+        lhs = -lhs;
+    }
+    let mut rhs = digits.clone();
+    let ten = num_bigint::BigInt::from(10);
+    if scale > 0 {
+        lhs *= ten.pow(scale as u32);
+    } else {
+        rhs *= ten.pow(scale.unsigned_abs() as u32);
+    }
+    if exponent > 0 {
+        lhs <<= exponent as usize;
+    } else {
+        rhs <<= exponent.unsigned_abs() as usize;
+    }
+    lhs == rhs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big_int(value: &str) -> BigInt {
+        BigInt::try_from(value.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_scale_big_int() {
+        assert_eq!(
+            scale_big_int(&big_int("1500000000000000000"), 18),
+            Scaled {
+                value: 1.5,
+                precision: Precision::Exact
+            }
+        );
+        assert_eq!(scale_big_int(&big_int("-250"), 2).value, -2.5);
+        assert!(scale_big_int(&BigInt::zero(), 18).is_exact());
+
+        // 0.1 has no exact f64 representation
+        let scaled = scale_big_int(&big_int("100000"), 6);
+        assert_eq!(scaled.value, 0.1);
+        assert_eq!(scaled.precision, Precision::Rounded);
+
+        // u256::MAX with 18 decimals is ~1.16e59
+        let max = big_int(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        );
+        let scaled = scale_big_int(&max, 18);
+        assert_eq!(scaled.value, 1.157920892373162e59);
+        assert_eq!(scaled.precision, Precision::Rounded);
+
+        let scaled = scale_big_int(&big_int("-1"), 0);
+        assert_eq!(scaled.value, -1.0);
+        assert!(scaled.is_exact());
+    }
+
+    #[test]
+    fn test_big_decimal_to_f64() {
+        let scaled = big_decimal_to_f64(&BigDecimal::try_from("1834.25").unwrap());
+        assert_eq!(scaled.value, 1834.25);
+        assert!(scaled.is_exact());
+
+        let scaled = big_decimal_to_f64(&BigDecimal::try_from("1e400").unwrap());
+        assert_eq!(scaled.value, f64::INFINITY);
+        assert_eq!(scaled.precision, Precision::Overflow);
+
+        let scaled = big_decimal_to_f64(&BigDecimal::try_from("-1e400").unwrap());
+        assert_eq!(scaled.value, f64::NEG_INFINITY);
+        assert_eq!(scaled.precision, Precision::Overflow);
+
+        let scaled = big_decimal_to_f64(&BigDecimal::try_from("1e-400").unwrap());
+        assert_eq!(scaled.value, 0.0);
+        assert_eq!(scaled.precision, Precision::Rounded);
+
+        // 2^-10
+        let scaled = big_decimal_to_f64(&BigDecimal::try_from("0.0009765625").unwrap());
+        assert_eq!(scaled.value, 0.0009765625);
+        assert!(scaled.is_exact());
+    }
+
+    #[test]
+    fn test_builders() {
+        let amount = BigInt::from(1_500_000u64);
+        let price = BigDecimal::try_from("1834.25").unwrap();
+        assert_eq!(
+            Counter::from("volume").add_scaled(&amount, 6),
+            Ok(Counter::from("volume").add(1.5))
+        );
+        assert_eq!(
+            Counter::from("volume").set_total_scaled(&amount, 6),
+            Ok(Counter::from("volume").set_total(1.5))
+        );
+        assert_eq!(
+            Gauge::from("price").set_decimal(&price),
+            Ok(Gauge::from("price").set(1834.25))
+        );
+        assert_eq!(
+            Histogram::from("size").observe_scaled(&amount, 6),
+            Ok(Histogram::from("size").observe(1.5))
+        );
+        assert_eq!(
+            Summary::from("size").observe_decimal(&price),
+            Ok(Summary::from("size").observe(1834.25))
+        );
+
+        // Rounded amounts are accepted, amounts out of range are not
+        assert!(Gauge::from("price")
+            .set_scaled(&BigInt::from(100_000u64), 6)
+            .is_ok());
+        let error = OverflowError {
+            value: f64::INFINITY,
+        };
+        let overflow = Err(error);
+        let huge = BigDecimal::try_from("1e400").unwrap();
+        let huge_amount = big_int(&format!("1{}", "0".repeat(400)));
+        assert_eq!(Counter::from("volume").add_decimal(&huge), overflow);
+        assert_eq!(
+            Counter::from("volume").set_total_scaled(&huge_amount, 0),
+            overflow
+        );
+        assert_eq!(Gauge::from("price").add_scaled(&huge_amount, 18), overflow);
+        assert_eq!(Histogram::from("size").observe_decimal(&huge), overflow);
+        assert_eq!(
+            Summary::from("size").observe_scaled(&huge_amount, 6),
+            overflow
+        );
+        assert_eq!(
+            Gauge::from("price").sub_decimal(&BigDecimal::try_from("-1e400").unwrap()),
+            Err(OverflowError {
+                value: f64::NEG_INFINITY
+            })
+        );
+        assert_eq!(error.to_string(), "amount out of the f64 range (inf)");
+    }
+}
@@ -0,0 +1,437 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, OnceLock};
+
+use prost::encoding::{
+    encode_key, encode_varint, encoded_len_varint, key_len, string, uint64, WireType,
+};
+
+use crate::{prometheus_operation, PrometheusOperation};
+
+/// Metric name and label set interned once, and encoded in protobuf wire format on first use
+/// (`name` and `labels` fields of `PrometheusOperation`, labels sorted by name).
+///
+/// Builders (`Counter`, `Gauge`, `Histogram` and `Summary`) keep their name and labels in a Series,
+/// and their methods return a `SeriesOperation` sharing it: building an operation clones an `Arc`,
+/// not the name and the labels `HashMap`.
+/// `PrometheusOperationsWriter::push` then only copies the pre-encoded bytes, while converting to a
+/// `PrometheusOperation` (ex: `PrometheusOperations::push`) copies the name and labels into the message.
+///
+/// ### Example
+/// ```
+/// use std::collections::HashMap;
+/// use prost::Message;
+/// use substreams_sink_prometheus::{prometheus_operation, CounterOp, PrometheusOperations, Series};
+/// let labels = HashMap::from([("label1".to_string(), "value1".to_string())]);
+/// let series = Series::new("counter_name", &labels);
+///
+/// let mut buf = vec![];
+/// series.encode(&prometheus_operation::Operation::Counter(CounterOp::inc()), &mut buf);
+/// series.encode(&prometheus_operation::Operation::Counter(CounterOp::add(2.0)), &mut buf);
+/// assert_eq!(PrometheusOperations::decode(buf.as_slice()).unwrap().operations.len(), 2);
+/// ```
+#[derive(Clone, Default)]
+pub struct Series(Arc<Interned>);
+
+#[derive(Default)]
+struct Interned {
+    name: String,
+    labels: HashMap<String, String>,
+    encoded: OnceLock<Box<[u8]>>,
+}
+
+impl Series {
+    /// Intern the name and labels of a series
+    pub fn new(name: &str, labels: &HashMap<String, String>) -> Self {
+        Self::from_parts(name.to_string(), labels.clone())
+    }
+
+    pub(crate) fn from_parts(name: String, labels: HashMap<String, String>) -> Self {
+        Self(Arc::new(Interned {
+            name,
+            labels,
+            encoded: OnceLock::new(),
+        }))
+    }
+
+    // Same name with other labels, reusing the name when the series isn't shared
+    pub(crate) fn with_labels(self, labels: HashMap<String, String>) -> Self {
+        let name = match Arc::try_unwrap(self.0) {
+            Ok(interned) => interned.name,
+            Err(interned) => interned.name.to_owned(),
+        };
+        Self::from_parts(name, labels)
+    }
+
+    /// Metric name
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    /// Labels
+    #[inline]
+    pub fn labels(&self) -> &HashMap<String, String> {
+        &self.0.labels
+    }
+
+    /// Pre-encoded `name` and `labels` fields
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.encoded.get_or_init(|| {
+            let mut encoded = Vec::with_capacity(name_labels_len(self.name(), self.labels()));
+            encode_name_labels(self.name(), self.labels(), &mut encoded);
+            encoded.into()
+        })
+    }
+
+    /// Append an entry of `PrometheusOperations.operations` for this series to `buf`.
+    ///
+    /// `buf` holds an encoded `PrometheusOperations` as long as only such entries are appended to it.
+    #[inline]
+    pub fn encode(&self, operation: &prometheus_operation::Operation, buf: &mut Vec<u8>) {
+        self.encode_entry(Some(operation), buf);
+    }
+
+    /// Encoded length of the entry appended by `encode`
+    #[inline]
+    pub fn encoded_len(&self, operation: &prometheus_operation::Operation) -> usize {
+        let len = self.as_bytes().len() + operation.encoded_len();
+        key_len(1) + encoded_len_varint(len as u64) + len
+    }
+
+    fn encode_entry(&self, operation: Option<&prometheus_operation::Operation>, buf: &mut Vec<u8>) {
+        let encoded = self.as_bytes();
+        let len = encoded.len() + operation.map_or(0, |operation| operation.encoded_len());
+        encode_key(1, WireType::LengthDelimited, buf);
+        encode_varint(len as u64, buf);
+        buf.extend_from_slice(encoded);
+        if let Some(operation) = operation {
+            operation.encode(buf);
+        }
+    }
+}
+
+impl PartialEq for Series {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+            || self.name() == other.name() && self.labels() == other.labels()
+    }
+}
+
+impl Eq for Series {}
+
+impl Hash for Series {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
+impl fmt::Debug for Series {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Series")
+            .field("name", &self.name())
+            .field("labels", self.labels())
+            .finish()
+    }
+}
+
+/// Operation on a `Series`, as returned by the builder methods.
+///
+/// It converts into a `PrometheusOperation` with `into()`, and is encoded without copying the name
+/// and labels by `PrometheusOperationsWriter::push` (see `Series`).
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{Counter, PrometheusOperation, PrometheusOperationsWriter};
+/// let mut counter = Counter::from("counter_name");
+///
+/// let mut writer = PrometheusOperationsWriter::new();
+/// writer.push(counter.inc());
+///
+/// let operation: PrometheusOperation = counter.inc().into();
+/// assert_eq!(operation.name, "counter_name");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeriesOperation {
+    pub series: Series,
+    pub operation: Option<prometheus_operation::Operation>,
+}
+
+impl SeriesOperation {
+    /// Append an entry of `PrometheusOperations.operations` to `buf` (see `Series::encode`)
+    #[inline]
+    pub fn encode(&self, buf: &mut Vec<u8>) {
+        self.series.encode_entry(self.operation.as_ref(), buf);
+    }
+}
+
+impl From<SeriesOperation> for PrometheusOperation {
+    fn from(operation: SeriesOperation) -> Self {
+        let (name, labels) = match Arc::try_unwrap(operation.series.0) {
+            Ok(interned) => (interned.name, interned.labels),
+            Err(interned) => (interned.name.to_owned(), interned.labels.to_owned()),
+        };
+        PrometheusOperation {
+            name,
+            labels,
+            operation: operation.operation,
+        }
+    }
+}
+
+impl From<PrometheusOperation> for SeriesOperation {
+    fn from(operation: PrometheusOperation) -> Self {
+        SeriesOperation {
+            series: Series::from_parts(operation.name, operation.labels),
+            operation: operation.operation,
+        }
+    }
+}
+
+impl PartialEq<PrometheusOperation> for SeriesOperation {
+    fn eq(&self, other: &PrometheusOperation) -> bool {
+        self.series.name() == other.name
+            && self.series.labels() == &other.labels
+            && self.operation == other.operation
+    }
+}
+
+impl PartialEq<SeriesOperation> for PrometheusOperation {
+    fn eq(&self, other: &SeriesOperation) -> bool {
+        other == self
+    }
+}
+
+/// Append an entry of `PrometheusOperations.operations` to `buf`, with labels sorted by name
+/// so the same operation always encodes to the same bytes.
+pub(crate) fn encode_sorted(operation: &PrometheusOperation, buf: &mut Vec<u8>) {
+    let op = operation.operation.as_ref();
+    let len =
+        name_labels_len(&operation.name, &operation.labels) + op.map_or(0, |op| op.encoded_len());
+    encode_key(1, WireType::LengthDelimited, buf);
+    encode_varint(len as u64, buf);
+    encode_name_labels(&operation.name, &operation.labels, buf);
+    if let Some(op) = op {
+        op.encode(buf);
+    }
+}
+
+// Same encoding as prost for the `name` and `labels` fields, except map entries are sorted by key.
+fn encode_name_labels(name: &str, labels: &HashMap<String, String>, buf: &mut Vec<u8>) {
+    if !name.is_empty() {
+        encode_key(1, WireType::LengthDelimited, buf);
+        encode_varint(name.len() as u64, buf);
+        buf.extend_from_slice(name.as_bytes());
+    }
+    encode_sorted_map(2, labels, buf);
+}
+
+/// Same encoding as prost for a `map<string, string>` field, except entries are sorted by key.
+pub(crate) fn encode_sorted_map(tag: u32, map: &HashMap<String, String>, buf: &mut Vec<u8>) {
+    let mut entries: Vec<(&String, &String)> = map.iter().collect();
+    entries.sort_unstable();
+    for (key, value) in entries {
+        encode_key(tag, WireType::LengthDelimited, buf);
+        encode_varint(label_len(key, value) as u64, buf);
+        if !key.is_empty() {
+            string::encode(1, key, buf);
+        }
+        if !value.is_empty() {
+            string::encode(2, value, buf);
+        }
+    }
+}
+
+/// Same encoding as prost for a `map<string, uint64>` field, except entries are sorted by key.
+pub(crate) fn encode_sorted_u64_map(tag: u32, map: &HashMap<String, u64>, buf: &mut Vec<u8>) {
+    let mut entries: Vec<(&String, &u64)> = map.iter().collect();
+    entries.sort_unstable();
+    for (key, value) in entries {
+        let key_len = match key.is_empty() {
+            true => 0,
+            false => string::encoded_len(1, key),
+        };
+        let value_len = match *value {
+            0 => 0,
+            _ => uint64::encoded_len(2, value),
+        };
+        encode_key(tag, WireType::LengthDelimited, buf);
+        encode_varint((key_len + value_len) as u64, buf);
+        if !key.is_empty() {
+            string::encode(1, key, buf);
+        }
+        if *value != 0 {
+            uint64::encode(2, value, buf);
+        }
+    }
+}
+
+fn name_labels_len(name: &str, labels: &HashMap<String, String>) -> usize {
+    let name_len = match name.len() {
+        0 => 0,
+        len => key_len(1) + encoded_len_varint(len as u64) + len,
+    };
+    let labels_len: usize = labels
+        .iter()
+        .map(|(key, value)| label_len(key, value))
+        .map(|len| key_len(2) + encoded_len_varint(len as u64) + len)
+        .sum();
+    name_len + labels_len
+}
+
+fn label_len(key: &String, value: &String) -> usize {
+    let key_len = if key.is_empty() {
+        0
+    } else {
+        string::encoded_len(1, key)
+    };
+    let value_len = if value.is_empty() {
+        0
+    } else {
+        string::encoded_len(2, value)
+    };
+    key_len + value_len
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{
+        cardinality::is_reset, Counter, GaugeOp, PrometheusOperation, PrometheusOperations,
+    };
+    use proptest::{collection::hash_map, prelude::*, test_runner::TestCaseError};
+    use prost::Message;
+
+    pub(crate) fn arb_name() -> impl Strategy<Value = String> {
+        "[a-z_]{1,16}"
+    }
+
+    pub(crate) fn arb_labels() -> impl Strategy<Value = HashMap<String, String>> {
+        hash_map("[a-z_]{1,8}", "\\PC{0,8}", 0..4)
+    }
+
+    /// Check the operations of a builder `from(name).with(labels)` against a case table of
+    /// `(operation, expected enum value, expected value)`: every operation has its own enum value,
+    /// never `OPERATION_UNSPECIFIED`, and survives a round trip through prost and `SeriesOperation::encode`.
+    pub(crate) fn check_operations(
+        name: &str,
+        labels: &HashMap<String, String>,
+        cases: impl IntoIterator<Item = (SeriesOperation, i32, Option<f64>)>,
+    ) -> Result<(), TestCaseError> {
+        for (operation, expected, expected_value) in cases {
+            let mut buf = vec![];
+            operation.encode(&mut buf);
+            let operation = PrometheusOperation::from(operation);
+            prop_assert_eq!(&operation.name, name);
+            if !is_reset(&operation) {
+                prop_assert_eq!(&operation.labels, labels);
+            }
+
+            let mut sorted = vec![];
+            encode_sorted(&operation, &mut sorted);
+            prop_assert_eq!(buf, sorted);
+
+            let decoded =
+                PrometheusOperation::decode(operation.encode_to_vec().as_slice()).unwrap();
+            let (op, value) = match decoded.operation {
+                Some(prometheus_operation::Operation::Counter(op)) => (op.operation, op.value),
+                Some(prometheus_operation::Operation::Gauge(op)) => (op.operation, op.value),
+                Some(prometheus_operation::Operation::Histogram(op)) => (op.operation, op.value),
+                Some(prometheus_operation::Operation::Summary(op)) => (op.operation, op.value),
+                None => return Err(TestCaseError::fail("operation is not set")),
+            };
+            prop_assert_ne!(op, 0, "OPERATION_UNSPECIFIED");
+            prop_assert_eq!(op, expected);
+            if let Some(expected_value) = expected_value {
+                prop_assert!(value == expected_value || value.is_nan() && expected_value.is_nan());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_series_encode() {
+        let labels = HashMap::from([
+            ("b".to_string(), "2".to_string()),
+            ("a".to_string(), "1".to_string()),
+            ("c".to_string(), "".to_string()),
+        ]);
+        let operation = prometheus_operation::Operation::Gauge(GaugeOp::set(88.8));
+        let series = Series::new("gauge_name", &labels);
+
+        let mut buf = vec![];
+        series.encode(&operation, &mut buf);
+        series.encode(&operation, &mut buf);
+        assert_eq!(buf.len(), 2 * series.encoded_len(&operation));
+
+        let expected = PrometheusOperation {
+            name: "gauge_name".to_string(),
+            labels,
+            operation: Some(operation),
+        };
+        assert_eq!(
+            PrometheusOperations::decode(buf.as_slice())
+                .unwrap()
+                .operations,
+            vec![expected.clone(), expected]
+        );
+        assert_eq!(Series::new("", &HashMap::new()), Series::default());
+    }
+
+    #[test]
+    fn test_encode_sorted() {
+        let operation = PrometheusOperation {
+            name: "gauge_name".to_string(),
+            labels: (0..20)
+                .map(|i| (format!("label{i}"), format!("value{i}")))
+                .collect(),
+            operation: Some(prometheus_operation::Operation::Gauge(GaugeOp::set(88.8))),
+        };
+        let mut reversed = operation.clone();
+        reversed.labels = (0..20)
+            .rev()
+            .map(|i| (format!("label{i}"), format!("value{i}")))
+            .collect();
+
+        let mut buf = vec![];
+        encode_sorted(&operation, &mut buf);
+        let mut other = vec![];
+        encode_sorted(&reversed, &mut other);
+        assert_eq!(buf, other);
+        assert_eq!(buf, {
+            let mut series = vec![];
+            Series::new(&operation.name, &operation.labels)
+                .encode(operation.operation.as_ref().unwrap(), &mut series);
+            series
+        });
+        assert_eq!(
+            PrometheusOperations::decode(buf.as_slice())
+                .unwrap()
+                .operations,
+            vec![operation]
+        );
+    }
+
+    #[test]
+    fn test_series_operation() {
+        let labels = HashMap::from([("label1".to_string(), "value1".to_string())]);
+        let mut counter = Counter::from("counter_name").with(labels.clone());
+        let inc = counter.inc();
+        let add = counter.add(2.0);
+        assert!(Arc::ptr_eq(&inc.series.0, &add.series.0));
+
+        let mut buf = vec![];
+        inc.encode(&mut buf);
+        add.encode(&mut buf);
+        let operations = PrometheusOperations::decode(buf.as_slice())
+            .unwrap()
+            .operations;
+        assert_eq!(operations, vec![inc.clone(), add]);
+
+        let operation = PrometheusOperation::from(inc.clone());
+        assert_eq!(operation.labels, labels);
+        assert_eq!(SeriesOperation::from(operation), inc);
+    }
+}
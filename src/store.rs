@@ -0,0 +1,219 @@
+use std::fmt;
+
+use substreams::pb::substreams::{store_delta, StoreDelta, StoreDeltas};
+
+use crate::{Counter, Gauge, PrometheusOperations, SeriesOperation};
+
+/// The new value of a store delta is not a finite number.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StoreValueError {
+    pub key: String,
+    pub value: Vec<u8>,
+}
+
+impl fmt::Display for StoreValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "store key {:?}: value {:?} is not a finite number",
+            self.key,
+            String::from_utf8_lossy(&self.value)
+        )
+    }
+}
+
+impl std::error::Error for StoreValueError {}
+
+/// Expose a store as gauges: created and updated keys `set` the gauge to the new value,
+/// deleted keys `remove` its series.
+///
+/// `parse_key` maps each store key to the Gauge (name and labels) of its series, or `None` to skip the key.
+/// Values must be finite numbers stored as strings, as written by the `int64`, `float64`, `bigint` and `bigdecimal` stores.
+///
+/// ### Example
+/// ```
+/// use substreams::pb::substreams::{store_delta, StoreDelta, StoreDeltas};
+/// use substreams_sink_prometheus::{gauges_from_store_deltas, labels, Gauge};
+/// let deltas = StoreDeltas {
+///     deltas: vec![StoreDelta {
+///         operation: store_delta::Operation::Create.into(),
+///         key: "balance:0xa".to_string(),
+///         new_value: b"100".to_vec(),
+///         ..Default::default()
+///     }],
+/// };
+/// let prom_ops = gauges_from_store_deltas(&deltas, |store_key| {
+///     let (name, holder) = store_key.split_once(':')?;
+///     Some(Gauge::from(name).with(labels! {"holder" => holder}))
+/// })
+/// .unwrap();
+/// assert_eq!(prom_ops.operations, vec![Gauge::from("balance").with(labels! {"holder" => "0xa"}).set(100.0)]);
+/// ```
+pub fn gauges_from_store_deltas<F>(
+    deltas: &StoreDeltas,
+    mut parse_key: F,
+) -> Result<PrometheusOperations, StoreValueError>
+where
+    F: FnMut(&str) -> Option<Gauge>,
+{
+    let mut prom_ops = PrometheusOperations::default();
+    for delta in &deltas.deltas {
+        let Some(mut gauge) = parse_key(&delta.key) else {
+            continue;
+        };
+        if let Some(operation) = gauge_from_store_delta(delta, &mut gauge)? {
+            prom_ops.push(operation);
+        }
+    }
+    Ok(prom_ops)
+}
+
+/// Operation of a single store delta for the given Gauge, `None` for unset operations.
+pub fn gauge_from_store_delta(
+    delta: &StoreDelta,
+    gauge: &mut Gauge,
+) -> Result<Option<SeriesOperation>, StoreValueError> {
+    match store_delta::Operation::from_i32(delta.operation) {
+        Some(store_delta::Operation::Create) | Some(store_delta::Operation::Update) => {
+            let value = parse_value(&delta.new_value).ok_or_else(|| StoreValueError {
+                key: delta.key.to_owned(),
+                value: delta.new_value.to_owned(),
+            })?;
+            Ok(Some(gauge.set(value)))
+        }
+        Some(store_delta::Operation::Delete) => Ok(Some(gauge.remove(gauge.labels().to_owned()))),
+        Some(store_delta::Operation::Unset) | None => Ok(None),
+    }
+}
+
+/// Expose the totals of a store as counters: created and updated keys `set_total` the counter
+/// to the new value, deleted keys `remove` its series.
+///
+/// Unlike `add` operations, the totals kept by an `add` store survive restarts of the sink.
+/// `parse_key` maps each store key to the Counter (name and labels) of its series, or `None` to skip the key.
+pub fn counters_from_store_deltas<F>(
+    deltas: &StoreDeltas,
+    mut parse_key: F,
+) -> Result<PrometheusOperations, StoreValueError>
+where
+    F: FnMut(&str) -> Option<Counter>,
+{
+    let mut prom_ops = PrometheusOperations::default();
+    for delta in &deltas.deltas {
+        let Some(mut counter) = parse_key(&delta.key) else {
+            continue;
+        };
+        if let Some(operation) = counter_from_store_delta(delta, &mut counter)? {
+            prom_ops.push(operation);
+        }
+    }
+    Ok(prom_ops)
+}
+
+/// Operation of a single store delta for the given Counter, `None` for unset operations.
+pub fn counter_from_store_delta(
+    delta: &StoreDelta,
+    counter: &mut Counter,
+) -> Result<Option<SeriesOperation>, StoreValueError> {
+    match store_delta::Operation::from_i32(delta.operation) {
+        Some(store_delta::Operation::Create) | Some(store_delta::Operation::Update) => {
+            let value = parse_value(&delta.new_value).ok_or_else(|| StoreValueError {
+                key: delta.key.to_owned(),
+                value: delta.new_value.to_owned(),
+            })?;
+            Ok(Some(counter.set_total(value)))
+        }
+        Some(store_delta::Operation::Delete) => {
+            Ok(Some(counter.remove(counter.labels().to_owned())))
+        }
+        Some(store_delta::Operation::Unset) | None => Ok(None),
+    }
+}
+
+// `parse` also accepts "NaN" and "inf", which would poison the series
+fn parse_value(value: &[u8]) -> Option<f64> {
+    let value: f64 = std::str::from_utf8(value).ok()?.trim().parse().ok()?;
+    value.is_finite().then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::labels;
+
+    fn delta(operation: store_delta::Operation, key: &str, value: &str) -> StoreDelta {
+        StoreDelta {
+            operation: operation.into(),
+            key: key.to_string(),
+            new_value: value.as_bytes().to_vec(),
+            ..Default::default()
+        }
+    }
+
+    fn parse_key(store_key: &str) -> Option<Gauge> {
+        let (name, holder) = store_key.split_once(':')?;
+        Some(Gauge::from(name).with(labels! {"holder" => holder}))
+    }
+
+    #[test]
+    fn test_gauges_from_store_deltas() {
+        let deltas = StoreDeltas {
+            deltas: vec![
+                delta(store_delta::Operation::Create, "balance:0xa", "100"),
+                delta(store_delta::Operation::Update, "balance:0xb", "12.5"),
+                delta(store_delta::Operation::Delete, "balance:0xc", ""),
+                delta(store_delta::Operation::Create, "total_supply", "1000"),
+                delta(store_delta::Operation::Unset, "balance:0xd", ""),
+            ],
+        };
+        let prom_ops = gauges_from_store_deltas(&deltas, parse_key).unwrap();
+
+        let mut expected = PrometheusOperations::default();
+        expected.push(parse_key("balance:0xa").unwrap().set(100.0));
+        expected.push(parse_key("balance:0xb").unwrap().set(12.5));
+        expected.push(Gauge::from("balance").remove(labels! {"holder" => "0xc"}));
+        assert_eq!(prom_ops.encode_sorted(), expected.encode_sorted());
+    }
+
+    #[test]
+    fn test_counters_from_store_deltas() {
+        let deltas = StoreDeltas {
+            deltas: vec![
+                delta(store_delta::Operation::Update, "volume:0xa", "1500"),
+                delta(store_delta::Operation::Delete, "volume:0xb", ""),
+                delta(store_delta::Operation::Unset, "volume:0xc", ""),
+            ],
+        };
+        let prom_ops = counters_from_store_deltas(&deltas, |store_key| {
+            let (_, holder) = store_key.split_once(':')?;
+            Some(Counter::from("volume_total").with(labels! {"holder" => holder}))
+        })
+        .unwrap();
+
+        let mut volume = Counter::from("volume_total").with(labels! {"holder" => "0xa"});
+        let mut expected = PrometheusOperations::default();
+        expected.push(volume.set_total(1500.0));
+        expected.push(volume.remove(labels! {"holder" => "0xb"}));
+        assert_eq!(prom_ops.encode_sorted(), expected.encode_sorted());
+    }
+
+    #[test]
+    fn test_store_value_error() {
+        for value in ["USDT", "NaN", "inf", "-infinity"] {
+            let deltas = StoreDeltas {
+                deltas: vec![delta(store_delta::Operation::Create, "balance:0xa", value)],
+            };
+            assert_eq!(
+                gauges_from_store_deltas(&deltas, parse_key),
+                Err(StoreValueError {
+                    key: "balance:0xa".to_string(),
+                    value: value.as_bytes().to_vec(),
+                })
+            );
+            assert!(
+                counters_from_store_deltas(&deltas, |_| Some(Counter::from("volume_total")))
+                    .is_err()
+            );
+        }
+    }
+}
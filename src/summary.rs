@@ -1,11 +1,13 @@
 use std::collections::HashMap;
 
-use crate::{PrometheusOperation, prometheus_operation, SummaryOp, summary_op};
+use crate::{prometheus_operation, Series, SeriesOperation, SummaryOp, summary_op, AggregatedObservations};
 
-#[derive(Eq, Debug, PartialEq, Default)]
+/// Builds the operations of a Summary series.
+///
+/// The methods return a `SeriesOperation` sharing the name and labels interned by `from` and `with`, see `Series`.
+#[derive(Clone, Eq, Debug, PartialEq, Default)]
 pub struct Summary {
-    pub name: String,
-    pub labels: HashMap<String, String>,
+    series: Series,
 }
 
 impl Summary {
@@ -20,8 +22,7 @@ impl Summary {
     #[must_use]
     pub fn from(name: &str) -> Self {
         Self {
-            name: name.to_string(),
-            labels: Default::default(),
+            series: Series::from_parts(name.to_string(), Default::default()),
         }
     }
 
@@ -38,10 +39,22 @@ impl Summary {
     /// ```
     #[inline]
     pub fn with(mut self, labels: HashMap<String, String>) -> Self {
-        self.labels = labels;
+        self.series = self.series.with_labels(labels);
         self
     }
 
+    /// Metric name of the Summary
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.series.name()
+    }
+
+    /// Labels set by `with`
+    #[inline]
+    pub fn labels(&self) -> &HashMap<String, String> {
+        self.series.labels()
+    }
+
     /// Observe adds a single observation to the summary.
     /// Observations are usually positive or zero.
     /// Negative observations are accepted but prevent current versions of Prometheus from properly detecting counter resets in the sum of observations
@@ -54,16 +67,43 @@ impl Summary {
     /// ```
     #[inline]
     #[must_use]
-    pub fn observe(&mut self, value: f64) -> PrometheusOperation {
-        let op = SummaryOp {
-            value,
-            operation: summary_op::Operation::Observe.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: self.labels.to_owned(),
-            operation: Some(prometheus_operation::Operation::Summary(op)),
-        }
+    pub fn observe(&mut self, value: f64) -> SeriesOperation {
+        self.operation(SummaryOp::observe(value))
+    }
+
+    /// Observe many values with a single operation, sharing the name and labels.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_prometheus::{PrometheusOperations, Summary};
+    /// let mut prom_ops: PrometheusOperations = Default::default();
+    /// prom_ops.push(Summary::from("summary_name").observe_many(&[12.5, 30.0, 18.2]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn observe_many(&mut self, values: &[f64]) -> SeriesOperation {
+        self.operation(SummaryOp::observe_many(values.to_vec(), vec![]))
+    }
+
+    /// Observe the same value `count` times with a single operation.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_prometheus::{PrometheusOperations, Summary};
+    /// let mut prom_ops: PrometheusOperations = Default::default();
+    /// prom_ops.push(Summary::from("summary_name").observe_n(21_000.0, 150));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn observe_n(&mut self, value: f64, count: u64) -> SeriesOperation {
+        self.operation(SummaryOp::observe_many(vec![value], vec![count]))
+    }
+
+    /// ObserveAggregated adds the observations aggregated in the module at once, see `SummaryAccumulator`.
+    #[inline]
+    #[must_use]
+    pub fn observe_aggregated(&mut self, aggregated: AggregatedObservations) -> SeriesOperation {
+        self.operation(SummaryOp::observe_aggregated(aggregated))
     }
 
     /// Start a timer. Calling the returned function will observe the duration in seconds in the summary.
@@ -76,15 +116,26 @@ impl Summary {
     /// ```
     #[inline]
     #[must_use]
-    pub fn start_timer(&mut self) -> PrometheusOperation {
-        let op = SummaryOp {
-            value: f64::NAN,
-            operation: summary_op::Operation::StartTimer.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: self.labels.to_owned(),
-            operation: Some(prometheus_operation::Operation::Summary(op)),
+    pub fn start_timer(&mut self) -> SeriesOperation {
+        self.operation(SummaryOp::start_timer())
+    }
+
+    /// Initialize the metrics for the given combination of labels to zero
+    ///
+    /// ### Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use substreams_sink_prometheus::{PrometheusOperations, Summary};
+    /// let mut prom_ops: PrometheusOperations = Default::default();
+    /// let labels = HashMap::from([("label1".to_string(), "value1".to_string())]);
+    /// prom_ops.push(Summary::from("summary_name").zero(labels));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn zero(&mut self, labels: HashMap<String, String>) -> SeriesOperation {
+        SeriesOperation {
+            series: Series::from_parts(self.name().to_owned(), labels),
+            operation: Some(prometheus_operation::Operation::Summary(SummaryOp::zero())),
         }
     }
 
@@ -100,15 +151,10 @@ impl Summary {
     /// ```
     #[inline]
     #[must_use]
-    pub fn remove(&mut self, labels: HashMap<String, String>) -> PrometheusOperation {
-        let op = SummaryOp {
-            value: f64::NAN,
-            operation: summary_op::Operation::Remove.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels,
-            operation: Some(prometheus_operation::Operation::Summary(op)),
+    pub fn remove(&mut self, labels: HashMap<String, String>) -> SeriesOperation {
+        SeriesOperation {
+            series: Series::from_parts(self.name().to_owned(), labels),
+            operation: Some(prometheus_operation::Operation::Summary(SummaryOp::remove())),
         }
     }
 
@@ -122,15 +168,100 @@ impl Summary {
     /// ```
     #[inline]
     #[must_use]
-    pub fn reset(&mut self) -> PrometheusOperation {
-        let op = SummaryOp {
+    pub fn reset(&mut self) -> SeriesOperation {
+        SeriesOperation {
+            series: Series::from_parts(self.name().to_owned(), Default::default()),
+            operation: Some(prometheus_operation::Operation::Summary(SummaryOp::reset())),
+        }
+    }
+
+    #[inline]
+    fn operation(&self, op: SummaryOp) -> SeriesOperation {
+        SeriesOperation {
+            series: self.series.clone(),
+            operation: Some(prometheus_operation::Operation::Summary(op)),
+        }
+    }
+}
+
+impl SummaryOp {
+    /// Observe adds a single observation to the summary.
+    #[inline]
+    #[must_use]
+    pub fn observe(value: f64) -> Self {
+        Self {
+            value,
+            operation: summary_op::Operation::Observe.into(),
+            ..Default::default()
+        }
+    }
+
+    /// ObserveAggregated adds the observations aggregated in the module at once.
+    #[inline]
+    #[must_use]
+    pub fn observe_aggregated(aggregated: AggregatedObservations) -> Self {
+        Self {
+            value: f64::NAN,
+            operation: summary_op::Operation::ObserveAggregated.into(),
+            aggregated: Some(aggregated),
+            ..Default::default()
+        }
+    }
+
+    /// ObserveMany adds every value, each `counts` times if set.
+    #[inline]
+    #[must_use]
+    pub fn observe_many(values: Vec<f64>, counts: Vec<u64>) -> Self {
+        Self {
+            value: f64::NAN,
+            operation: summary_op::Operation::ObserveMany.into(),
+            values,
+            counts,
+            ..Default::default()
+        }
+    }
+
+    /// Start a timer. Calling the returned function will observe the duration in seconds in the summary.
+    #[inline]
+    #[must_use]
+    pub fn start_timer() -> Self {
+        Self {
+            value: f64::NAN,
+            operation: summary_op::Operation::StartTimer.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Initialize the metrics for the given combination of labels to zero
+    #[inline]
+    #[must_use]
+    pub fn zero() -> Self {
+        Self {
+            value: f64::NAN,
+            operation: summary_op::Operation::Zero.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Remove metrics for the given label values
+    #[inline]
+    #[must_use]
+    pub fn remove() -> Self {
+        Self {
+            value: f64::NAN,
+            operation: summary_op::Operation::Remove.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Reset all values in the summary
+    #[inline]
+    #[must_use]
+    pub fn reset() -> Self {
+        Self {
             value: f64::NAN,
             operation: summary_op::Operation::Reset.into(),
-        };
-        PrometheusOperation {
-            name: self.name.to_owned(),
-            labels: Default::default(),
-            operation: Some(prometheus_operation::Operation::Summary(op)),
+            ..Default::default()
         }
     }
 }
@@ -138,7 +269,10 @@ impl Summary {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::series::tests::{arb_labels, arb_name, check_operations};
     use crate::PrometheusOperations;
+    use prost::Message;
+    use proptest::prelude::*;
     use std::collections::HashMap;
 
     #[test]
@@ -154,4 +288,33 @@ mod tests {
 
         assert_eq!(prom_ops.operations.len(), 4);
     }
+
+    #[test]
+    fn test_summary_encode() {
+        let labels = HashMap::from([("label1".to_string(), "value1".to_string())]);
+        let mut summary = Summary::from("summary_name").with(labels);
+
+        let mut buf = vec![];
+        summary.observe(88.8).encode(&mut buf);
+        summary.observe(1.5).encode(&mut buf);
+        let prom_ops = PrometheusOperations::decode(buf.as_slice()).unwrap();
+        assert_eq!(prom_ops.operations, vec![summary.observe(88.8), summary.observe(1.5)]);
+    }
+
+    proptest! {
+        #[test]
+        fn test_summary_operations(name in arb_name(), labels in arb_labels(), value in any::<f64>()) {
+            let mut summary = Summary::from(&name).with(labels.clone());
+            check_operations(&name, &labels, [
+                (summary.observe(value), summary_op::Operation::Observe as i32, Some(value)),
+                (summary.observe_aggregated(Default::default()), summary_op::Operation::ObserveAggregated as i32, None),
+                (summary.observe_many(&[value]), summary_op::Operation::ObserveMany as i32, None),
+                (summary.observe_n(value, 3), summary_op::Operation::ObserveMany as i32, None),
+                (summary.start_timer(), summary_op::Operation::StartTimer as i32, None),
+                (summary.zero(labels.clone()), summary_op::Operation::Zero as i32, None),
+                (summary.remove(labels.clone()), summary_op::Operation::Remove as i32, None),
+                (summary.reset(), summary_op::Operation::Reset as i32, None),
+            ])?;
+        }
+    }
 }
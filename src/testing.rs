@@ -0,0 +1,373 @@
+//! Assertions for unit tests of map modules, evaluated against the net effect of a
+//! `PrometheusOperations` batch (as applied by a `Registry`) instead of the individual operations.
+//!
+//! ### Example
+//! ```
+//! use substreams_sink_prometheus::{assert_counter, assert_gauge_set, assert_no_op, labels};
+//! use substreams_sink_prometheus::{Counter, Gauge, PrometheusOperations};
+//! let mut prom_ops: PrometheusOperations = Default::default();
+//! let mut transfers = Counter::from("transfers_total").with(labels! {"token" => "USDT"});
+//! prom_ops.push(transfers.inc());
+//! prom_ops.push(transfers.add(2.0));
+//! prom_ops.push(Gauge::from("head_block_number").set(17_000_000.0));
+//!
+//! assert_counter!(prom_ops, "transfers_total", labels! {"token" => "USDT"}, 3.0);
+//! assert_gauge_set!(prom_ops, "head_block_number", 17_000_000.0);
+//! assert_no_op!(prom_ops, "swaps_total");
+//! ```
+//!
+//! `assert_snapshot!` compares the rendered `/metrics` text to a file stored under `tests/snapshots`,
+//! set `UPDATE_SNAPSHOTS=1` to create it or accept the changes.
+use std::collections::HashMap;
+use std::path::Path;
+use std::{env, fs};
+
+use crate::{registry::to_labels, PrometheusOperations, Registry, Value};
+
+/// Assert the net value of a counter series after applying the batch.
+/// Labels default to none.
+#[macro_export]
+macro_rules! assert_counter {
+    ($operations:expr, $name:expr, $labels:expr, $value:expr $(,)?) => {
+        $crate::testing::assert_counter(&$operations, $name, &$labels, $value)
+    };
+    ($operations:expr, $name:expr, $value:expr $(,)?) => {
+        $crate::testing::assert_counter(&$operations, $name, &$crate::labels! {}, $value)
+    };
+}
+
+/// Assert the net value of a gauge series after applying the batch.
+/// Labels default to none.
+#[macro_export]
+macro_rules! assert_gauge_set {
+    ($operations:expr, $name:expr, $labels:expr, $value:expr $(,)?) => {
+        $crate::testing::assert_gauge(&$operations, $name, &$labels, $value)
+    };
+    ($operations:expr, $name:expr, $value:expr $(,)?) => {
+        $crate::testing::assert_gauge(&$operations, $name, &$crate::labels! {}, $value)
+    };
+}
+
+/// Assert that the batch has no operation for the metric.
+#[macro_export]
+macro_rules! assert_no_op {
+    ($operations:expr, $name:expr $(,)?) => {
+        $crate::testing::assert_no_op(&$operations, $name)
+    };
+}
+
+/// Assert that the text exposition of the batches, applied in order, matches the snapshot
+/// `tests/snapshots/<name>.prom` of the calling crate.
+///
+/// A missing snapshot fails the assertion like a mismatch; set `UPDATE_SNAPSHOTS=1` to write
+/// missing snapshots and overwrite the ones that don't match.
+/// Avoid `SetToCurrentTime` operations, their value changes on every run.
+///
+/// ### Example
+/// ```no_run
+/// use substreams_sink_prometheus::{assert_snapshot, Counter, PrometheusOperations};
+/// let mut block1: PrometheusOperations = Default::default();
+/// let mut block2: PrometheusOperations = Default::default();
+/// let mut transfers = Counter::from("transfers_total");
+/// block1.push(transfers.inc());
+/// block2.push(transfers.inc());
+/// assert_snapshot!("transfers", block1, block2);
+/// ```
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $($operations:expr),+ $(,)?) => {
+        $crate::testing::assert_snapshot(
+            &::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("snapshots")
+                .join(format!("{}.prom", $name)),
+            &$crate::testing::render(&[$(&$operations),+]),
+        )
+    };
+}
+
+/// Text exposition of the batches applied in order to a new `Registry`, panicking on invalid operations.
+#[track_caller]
+pub fn render(batches: &[&PrometheusOperations]) -> String {
+    let mut registry = Registry::new();
+    for operations in batches {
+        if let Err(err) = registry.apply_all(operations) {
+            panic!("invalid operations: {err}");
+        }
+    }
+    registry.to_text()
+}
+
+/// Compare `actual` to the snapshot file at `path`, panicking with a line diff on mismatch.
+#[track_caller]
+pub fn assert_snapshot(path: &Path, actual: &str) {
+    let update = env::var("UPDATE_SNAPSHOTS").map_or(false, |value| value == "1");
+    check_snapshot(path, actual, update);
+}
+
+#[track_caller]
+fn check_snapshot(path: &Path, actual: &str, update: bool) {
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) if expected == actual => return,
+        _ if update => return write_snapshot(path, actual),
+        Ok(expected) => expected,
+        Err(err) => panic!(
+            "snapshot {}: {err}, run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        ),
+    };
+    panic!(
+        "snapshot {} does not match (-expected +actual), run with UPDATE_SNAPSHOTS=1 to update it:\n{}",
+        path.display(),
+        diff(&expected, actual)
+    );
+}
+
+#[track_caller]
+fn write_snapshot(path: &Path, actual: &str) {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).unwrap_or_else(|err| panic!("{}: {err}", dir.display()));
+    }
+    fs::write(path, actual).unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+}
+
+// Above this many lines expected times actual lines, the changed lines are reported as removed then added
+// instead of allocating the longest common subsequence table.
+const DIFF_MAX_CELLS: usize = 1 << 20;
+
+// Line diff based on the longest common subsequence, unchanged lines are prefixed with a space.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    // The common prefix and suffix are unchanged, only the lines between them are compared
+    let prefix = expected
+        .iter()
+        .zip(&actual)
+        .take_while(|(expected, actual)| expected == actual)
+        .count();
+    let suffix = expected[prefix..]
+        .iter()
+        .rev()
+        .zip(actual[prefix..].iter().rev())
+        .take_while(|(expected, actual)| expected == actual)
+        .count();
+    let mut out = String::new();
+    for line in &expected[..prefix] {
+        out += &format!(" {line}\n");
+    }
+    let changed_expected = &expected[prefix..expected.len() - suffix];
+    let changed_actual = &actual[prefix..actual.len() - suffix];
+    match changed_expected.len().checked_mul(changed_actual.len()) {
+        Some(cells) if cells <= DIFF_MAX_CELLS => {
+            out += &lcs_diff(changed_expected, changed_actual)
+        }
+        _ => {
+            for line in changed_expected {
+                out += &format!("-{line}\n");
+            }
+            for line in changed_actual {
+                out += &format!("+{line}\n");
+            }
+        }
+    }
+    for line in &expected[expected.len() - suffix..] {
+        out += &format!(" {line}\n");
+    }
+    out
+}
+
+fn lcs_diff(expected: &[&str], actual: &[&str]) -> String {
+    let (n, m) = (expected.len(), actual.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && expected[i] == actual[j] {
+            out += &format!(" {}\n", expected[i]);
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out += &format!("-{}\n", expected[i]);
+            i += 1;
+        } else {
+            out += &format!("+{}\n", actual[j]);
+            j += 1;
+        }
+    }
+    out
+}
+
+/// Apply the batch to a new `Registry`, panicking on invalid operations.
+#[track_caller]
+pub fn registry(operations: &PrometheusOperations) -> Registry {
+    let mut registry = Registry::new();
+    if let Err(err) = registry.apply_all(operations) {
+        panic!("invalid operations: {err}");
+    }
+    registry
+}
+
+#[track_caller]
+pub fn assert_counter(
+    operations: &PrometheusOperations,
+    name: &str,
+    labels: &HashMap<String, String>,
+    expected: f64,
+) {
+    match registry(operations).get(name, &to_labels(labels)) {
+        Some(Value::Counter(value)) if *value == expected => {}
+        Some(Value::Counter(value)) => {
+            panic!("counter {name} {labels:?}: expected {expected}, found {value}")
+        }
+        other => panic!("counter {name} {labels:?}: expected {expected}, found {other:?}"),
+    }
+}
+
+#[track_caller]
+pub fn assert_gauge(
+    operations: &PrometheusOperations,
+    name: &str,
+    labels: &HashMap<String, String>,
+    expected: f64,
+) {
+    match registry(operations).get(name, &to_labels(labels)) {
+        Some(Value::Gauge(value)) if *value == expected => {}
+        Some(Value::Gauge(value)) => {
+            panic!("gauge {name} {labels:?}: expected {expected}, found {value}")
+        }
+        other => panic!("gauge {name} {labels:?}: expected {expected}, found {other:?}"),
+    }
+}
+
+#[track_caller]
+pub fn assert_no_op(operations: &PrometheusOperations, name: &str) {
+    let count = operations
+        .operations
+        .iter()
+        .filter(|operation| operation.name == name)
+        .count();
+    if count > 0 {
+        panic!("{name}: expected no operation, found {count}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff;
+    use crate::{labels, Counter, Gauge, Histogram, PrometheusOperations};
+
+    #[test]
+    fn test_assertions() {
+        let mut prom_ops: PrometheusOperations = Default::default();
+        let mut counter = Counter::from("transfers_total").with(labels! {"token" => "USDT"});
+        let mut gauge = Gauge::from("balance").with(labels! {"holder" => "0xa"});
+        prom_ops.push(counter.inc());
+        prom_ops.push(counter.add(2.0));
+        prom_ops.push(gauge.set(10.0));
+        prom_ops.push(gauge.sub(4.0));
+
+        assert_counter!(
+            prom_ops,
+            "transfers_total",
+            labels! {"token" => "USDT"},
+            3.0
+        );
+        assert_gauge_set!(prom_ops, "balance", labels! {"holder" => "0xa"}, 6.0);
+        assert_no_op!(prom_ops, "swaps_total");
+    }
+
+    #[test]
+    #[should_panic(expected = "counter transfers_total {}: expected 1, found None")]
+    fn test_assert_counter_missing() {
+        let mut prom_ops: PrometheusOperations = Default::default();
+        prom_ops.push(
+            Counter::from("transfers_total")
+                .with(labels! {"token" => "USDT"})
+                .inc(),
+        );
+        assert_counter!(prom_ops, "transfers_total", 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "gauge balance {}: expected 1, found 2")]
+    fn test_assert_gauge_value() {
+        let mut prom_ops: PrometheusOperations = Default::default();
+        prom_ops.push(Gauge::from("balance").set(2.0));
+        assert_gauge_set!(prom_ops, "balance", 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "transfers_total: expected no operation, found 1")]
+    fn test_assert_no_op() {
+        let mut prom_ops: PrometheusOperations = Default::default();
+        prom_ops.push(Counter::from("transfers_total").inc());
+        assert_no_op!(prom_ops, "transfers_total");
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut block1: PrometheusOperations = Default::default();
+        let mut block2: PrometheusOperations = Default::default();
+        let mut transfers = Counter::from("transfers_total").with(labels! {"token" => "USDT"});
+        let mut balance = Gauge::from("balance").with(labels! {"holder" => "0xa"});
+        let mut duration = Histogram::from("block_duration_seconds");
+        block1.push(transfers.inc());
+        block1.push(balance.set(10.0));
+        block1.push(duration.observe(0.2));
+        block2.push(transfers.add(2.0));
+        block2.push(balance.sub(4.0));
+        block2.push(duration.observe(3.0));
+
+        assert_snapshot!("testing", block1, block2);
+    }
+
+    #[test]
+    #[should_panic(expected = "run with UPDATE_SNAPSHOTS=1 to create it")]
+    fn test_missing_snapshot() {
+        // Never created, and checked without UPDATE_SNAPSHOTS so the update mode can't write it
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!(
+            "substreams_sink_prometheus_{}_{nanos}",
+            std::process::id()
+        ));
+        super::check_snapshot(
+            &dir.join("missing.prom"),
+            "# TYPE balance gauge\nbalance 1\n",
+            false,
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            diff("a 1\nb 2\nc 3\n", "a 1\nb 4\nc 3\nd 5\n"),
+            " a 1\n-b 2\n+b 4\n c 3\n+d 5\n"
+        );
+
+        // Too many changed lines for the table
+        let lines = |prefix: &str| {
+            (0..2000)
+                .map(|i| format!("{prefix} {i}\n"))
+                .collect::<Vec<_>>()
+        };
+        let diff = diff(
+            &format!("x\n{}y\n", lines("a").concat()),
+            &format!("x\n{}y\n", lines("b").concat()),
+        );
+        assert!(diff.starts_with(" x\n-a 0\n-a 1\n"));
+        assert!(diff.contains("-a 1999\n+b 0\n"));
+        assert!(diff.ends_with("+b 1999\n y\n"));
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::VecDeque;
+
+use crate::{Gauge, SeriesOperation};
+
+/// Moving sum and average of the values of the last `blocks` blocks, exposed as a Gauge.
+///
+/// Prometheus `rate()` over counters updated at irregular block times gives jagged graphs,
+/// computing "transfers in the last 100 blocks" in the module gives cleaner dashboards.
+///
+/// The window lives in memory; modules that don't keep state between blocks rebuild it with `load`
+/// from a store holding the value of each block under `window_key`.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_prometheus::{Gauge, PrometheusOperations, RollingWindow};
+/// let mut window = RollingWindow::new(Gauge::from("transfers_last_100_blocks"), 100);
+/// window.push(17_000_000, 12.0);
+/// window.push(17_000_001, 8.0);
+///
+/// let mut prom_ops: PrometheusOperations = Default::default();
+/// prom_ops.push(window.set_sum());
+/// assert_eq!(window.sum(), 20.0);
+/// assert_eq!(window.average(), 10.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollingWindow {
+    gauge: Gauge,
+    blocks: u64,
+    first_block: Option<u64>,
+    head: u64,
+    values: VecDeque<(u64, f64)>,
+}
+
+impl RollingWindow {
+    /// Create new empty RollingWindow over the last `blocks` blocks, setting `gauge`.
+    ///
+    /// Panics if `blocks` is 0.
+    #[inline]
+    #[must_use]
+    pub fn new(gauge: Gauge, blocks: u64) -> Self {
+        assert!(blocks > 0, "rolling window must span at least 1 block");
+        Self {
+            gauge,
+            blocks,
+            first_block: None,
+            head: 0,
+            values: Default::default(),
+        }
+    }
+
+    /// Rebuild the window ending at `block_number` from the value of each block, as returned by `get`
+    /// (ex: `|number| store.get_last(window_key("transfers", number))`).
+    ///
+    /// Like with `push`, the window starts at the first block with a value for `average`.
+    ///
+    /// ### Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use substreams_sink_prometheus::{window_key, Gauge, RollingWindow};
+    /// let store = HashMap::from([(window_key("transfers", 9), 3.0), (window_key("transfers", 10), 5.0)]);
+    /// let window = RollingWindow::load(Gauge::from("transfers_last_10_blocks"), 10, 10, |number| {
+    ///     store.get(&window_key("transfers", number)).copied()
+    /// });
+    /// assert_eq!(window.sum(), 8.0);
+    /// ```
+    pub fn load<F>(gauge: Gauge, blocks: u64, block_number: u64, mut get: F) -> Self
+    where
+        F: FnMut(u64) -> Option<f64>,
+    {
+        let mut window = Self::new(gauge, blocks);
+        let first_block = block_number.saturating_sub(blocks - 1);
+        for number in first_block..=block_number {
+            if let Some(value) = get(number) {
+                window.push(number, value);
+            }
+        }
+        window.advance(block_number);
+        window
+    }
+
+    /// Add `value` to block `block_number`, moving the window to it if it's a new block.
+    /// Values of earlier blocks still in the window are added to them.
+    pub fn push(&mut self, block_number: u64, value: f64) {
+        self.advance(block_number);
+        if self.head.saturating_sub(block_number) >= self.blocks {
+            return;
+        }
+        // Values stay sorted by block so that `advance` drops the oldest ones first
+        match self
+            .values
+            .binary_search_by_key(&block_number, |(number, _)| *number)
+        {
+            Ok(index) => self.values[index].1 += value,
+            Err(index) => self.values.insert(index, (block_number, value)),
+        }
+    }
+
+    /// Move the window to end at `block_number`, dropping the values of the blocks before it.
+    /// Blocks without `push` count as 0.
+    pub fn advance(&mut self, block_number: u64) {
+        self.first_block.get_or_insert(block_number);
+        self.head = self.head.max(block_number);
+        while self.values.front().map_or(false, |(number, _)| {
+            self.head.saturating_sub(*number) >= self.blocks
+        }) {
+            self.values.pop_front();
+        }
+    }
+
+    /// Sum of the values of the blocks in the window
+    pub fn sum(&self) -> f64 {
+        self.values.iter().map(|(_, value)| value).sum()
+    }
+
+    /// Average value per block in the window, the blocks before the first one seen are not counted
+    pub fn average(&self) -> f64 {
+        match self.first_block {
+            Some(first_block) => {
+                let blocks = (self.head - first_block).saturating_add(1).min(self.blocks);
+                self.sum() / blocks as f64
+            }
+            None => 0.0,
+        }
+    }
+
+    /// Sets the Gauge to the sum of the window
+    #[inline]
+    #[must_use]
+    pub fn set_sum(&mut self) -> SeriesOperation {
+        let sum = self.sum();
+        self.gauge.set(sum)
+    }
+
+    /// Sets the Gauge to the average per block of the window
+    #[inline]
+    #[must_use]
+    pub fn set_average(&mut self) -> SeriesOperation {
+        let average = self.average();
+        self.gauge.set(average)
+    }
+}
+
+/// Store key of the value of block `block_number` for a `RollingWindow`.
+///
+/// The block number is zero-padded so that `delete_prefix` of a block expiring from the window
+/// doesn't match the keys of later blocks.
+pub fn window_key(prefix: &str, block_number: u64) -> String {
+    format!("{prefix}:{block_number:020}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_rolling_window() {
+        let mut window = RollingWindow::new(Gauge::from("transfers_last_3_blocks"), 3);
+        assert_eq!(window.average(), 0.0);
+
+        window.push(10, 1.0);
+        window.push(10, 2.0);
+        window.push(11, 3.0);
+        assert_eq!(window.sum(), 6.0);
+        assert_eq!(window.average(), 3.0);
+
+        // Block #12 has no value, #10 leaves the window at #13
+        window.advance(12);
+        assert_eq!(window.sum(), 6.0);
+        assert_eq!(window.average(), 2.0);
+        window.push(13, 4.0);
+        assert_eq!(window.sum(), 7.0);
+
+        // Values of blocks out of the window are ignored
+        window.push(10, 100.0);
+        assert_eq!(window.sum(), 7.0);
+
+        // Late blocks leave the window in order
+        let mut late = RollingWindow::new(Gauge::from("transfers_last_3_blocks"), 3);
+        late.push(11, 1.0);
+        late.push(13, 1.0);
+        late.push(12, 1.0);
+        late.push(13, 1.0);
+        assert_eq!(late.sum(), 4.0);
+        late.advance(15);
+        assert_eq!(late.sum(), 2.0);
+        late.advance(16);
+        assert_eq!(late.sum(), 0.0);
+
+        window.advance(20);
+        assert_eq!(window.sum(), 0.0);
+        assert_eq!(
+            window.set_sum(),
+            Gauge::from("transfers_last_3_blocks").set(0.0)
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let store: HashMap<String, f64> = (1..=10)
+            .map(|number| (window_key("transfers", number), number as f64))
+            .collect();
+        let get = |number| store.get(&window_key("transfers", number)).copied();
+
+        let window = RollingWindow::load(Gauge::from("transfers"), 3, 10, get);
+        assert_eq!(window.sum(), 27.0);
+        assert_eq!(window.average(), 9.0);
+
+        // Before the window is full, counted from the first block with a value like `push`
+        let window = RollingWindow::load(Gauge::from("transfers"), 100, 1, get);
+        assert_eq!(window.sum(), 1.0);
+        assert_eq!(window.average(), 1.0);
+        let window = RollingWindow::load(Gauge::from("transfers"), 100, 12, get);
+        let mut pushed = RollingWindow::new(Gauge::from("transfers"), 100);
+        for number in 1..=10 {
+            pushed.push(number, number as f64);
+        }
+        pushed.advance(12);
+        assert_eq!(window.average(), pushed.average());
+        assert_eq!(window.average(), 55.0 / 12.0);
+
+        // Block numbers near u64::MAX don't overflow
+        let mut window = RollingWindow::new(Gauge::from("transfers"), 100);
+        window.push(u64::MAX - 1, 1.0);
+        window.push(u64::MAX, 2.0);
+        assert_eq!(window.sum(), 3.0);
+        assert_eq!(window.average(), 1.5);
+        let window = RollingWindow::load(Gauge::from("transfers"), 100, u64::MAX, |_| Some(1.0));
+        assert_eq!(window.sum(), 100.0);
+
+        assert_eq!(window_key("transfers", 1), "transfers:00000000000000000001");
+    }
+}
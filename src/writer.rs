@@ -0,0 +1,294 @@
+use prost::bytes::{Buf, BufMut};
+use std::collections::HashMap;
+
+use prost::encoding::{self, message, DecodeContext, WireType};
+use prost::{DecodeError, Message};
+
+use crate::{
+    series::{encode_sorted_map, encode_sorted_u64_map},
+    PrometheusOperation, PrometheusOperations, SeriesOperation,
+};
+
+/// Encodes operations in protobuf wire format as they are pushed, instead of collecting
+/// `PrometheusOperation` structs and encoding them all at the end.
+///
+/// The bytes are an encoded `PrometheusOperations`, and the writer implements `prost::Message`
+/// so it can be returned as is from a map handler in place of `PrometheusOperations`.
+/// Labels are always encoded sorted by name, so the output is deterministic.
+///
+/// ### Example
+/// ```
+/// use prost::Message;
+/// use substreams_sink_prometheus::{Counter, CounterOp, Gauge, PrometheusOperations, PrometheusOperationsWriter};
+/// let mut writer = PrometheusOperationsWriter::with_capacity(1024);
+/// let mut counter = Counter::from("counter_name");
+///
+/// // Only the pre-encoded name and labels of the counter are copied
+/// writer.push(counter.inc());
+/// writer.push(Gauge::from("gauge_name").set(88.8));
+///
+/// let prom_ops = PrometheusOperations::decode(writer.as_bytes()).unwrap();
+/// assert_eq!(prom_ops.operations.len(), 2);
+/// ```
+#[derive(Clone, Eq, Debug, PartialEq, Default)]
+pub struct PrometheusOperationsWriter {
+    buf: Vec<u8>,
+    len: usize,
+    final_only: bool,
+}
+
+impl PrometheusOperationsWriter {
+    /// Create new empty PrometheusOperationsWriter
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create new empty PrometheusOperationsWriter with a buffer of `capacity` bytes allocated up front
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+            len: 0,
+            final_only: false,
+        }
+    }
+
+    /// Mark the operations to be applied only once their block is final (see `PrometheusOperations::final_only`)
+    #[inline]
+    #[must_use]
+    pub fn final_only(mut self) -> Self {
+        self.set_final_only();
+        self
+    }
+
+    /// Set the Pushgateway job of the batch (see `PrometheusOperations::with_job`)
+    #[inline]
+    #[must_use]
+    pub fn with_job(mut self, job: &str) -> Self {
+        encoding::string::encode(3, &job.to_string(), &mut self.buf);
+        self
+    }
+
+    /// Set the Pushgateway grouping key of the batch
+    #[inline]
+    #[must_use]
+    pub fn with_grouping_key(mut self, grouping_key: &HashMap<String, String>) -> Self {
+        encode_sorted_map(4, grouping_key, &mut self.buf);
+        self
+    }
+
+    /// Replace every metric of the Pushgateway group, instead of only the metrics with the same names as the batch
+    #[inline]
+    #[must_use]
+    pub fn replace_group(mut self) -> Self {
+        encoding::bool::encode(5, &true, &mut self.buf);
+        self
+    }
+
+    /// Mark the series of the metric `name` as its complete set (see `PrometheusOperations::snapshot`)
+    #[inline]
+    #[must_use]
+    pub fn snapshot(mut self, name: &str) -> Self {
+        encoding::string::encode(6, &name.to_string(), &mut self.buf);
+        self
+    }
+
+    /// Remove the series of the metric `name` once they receive no update for `blocks` blocks,
+    /// enforced by the sink (see `PrometheusOperations::with_ttl_blocks`)
+    #[inline]
+    #[must_use]
+    pub fn with_ttl_blocks(mut self, name: &str, blocks: u64) -> Self {
+        let ttl = HashMap::from([(name.to_string(), blocks)]);
+        encode_sorted_u64_map(7, &ttl, &mut self.buf);
+        self
+    }
+
+    /// Remove the series of the metric `name` once they receive no update for `seconds` seconds,
+    /// enforced by the sink (see `PrometheusOperations::with_ttl_seconds`)
+    #[inline]
+    #[must_use]
+    pub fn with_ttl_seconds(mut self, name: &str, seconds: u64) -> Self {
+        let ttl = HashMap::from([(name.to_string(), seconds)]);
+        encode_sorted_u64_map(8, &ttl, &mut self.buf);
+        self
+    }
+
+    /// Whether the operations are marked `final_only`
+    #[inline]
+    pub fn is_final_only(&self) -> bool {
+        self.final_only
+    }
+
+    // The flag is written right away, fields can appear in any order
+    fn set_final_only(&mut self) {
+        if !self.final_only {
+            encoding::bool::encode(2, &true, &mut self.buf);
+            self.final_only = true;
+        }
+    }
+
+    /// Encode the operation, labels sorted by name.
+    ///
+    /// Operations returned by the builders only copy the name and labels encoded once per builder (see `Series`).
+    #[inline]
+    pub fn push(&mut self, operation: impl Into<SeriesOperation>) {
+        operation.into().encode(&mut self.buf);
+        self.len += 1;
+    }
+
+    /// Encode the operations
+    pub fn extend<T: Into<SeriesOperation>>(&mut self, operations: impl IntoIterator<Item = T>) {
+        for operation in operations {
+            self.push(operation);
+        }
+    }
+
+    /// Number of operations written
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no operation was written
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Encoded `PrometheusOperations`
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Take the buffer, an encoded `PrometheusOperations`
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Decode the written operations
+    pub fn to_operations(&self) -> Result<PrometheusOperations, DecodeError> {
+        PrometheusOperations::decode(self.as_bytes())
+    }
+}
+
+impl From<PrometheusOperations> for PrometheusOperationsWriter {
+    fn from(operations: PrometheusOperations) -> Self {
+        let mut writer = Self::new();
+        writer.extend(operations.operations);
+        if operations.final_only {
+            writer.set_final_only();
+        }
+        if !operations.job.is_empty() {
+            writer = writer.with_job(&operations.job);
+        }
+        writer = writer.with_grouping_key(&operations.grouping_key);
+        if operations.replace_group {
+            writer = writer.replace_group();
+        }
+        for name in &operations.snapshot {
+            writer = writer.snapshot(name);
+        }
+        encode_sorted_u64_map(7, &operations.ttl_blocks, &mut writer.buf);
+        encode_sorted_u64_map(8, &operations.ttl_seconds, &mut writer.buf);
+        writer
+    }
+}
+
+impl Message for PrometheusOperationsWriter {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        buf.put_slice(&self.buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        if tag == 1 {
+            let mut operation = PrometheusOperation::default();
+            message::merge(wire_type, &mut operation, buf, ctx)?;
+            self.push(operation);
+            Ok(())
+        } else {
+            // Batch fields (final_only, job, ...) are decoded on their own and encoded back as is
+            let mut fields = PrometheusOperations::default();
+            fields.merge_field(tag, wire_type, buf, ctx)?;
+            if fields.final_only {
+                fields.final_only = false;
+                self.set_final_only();
+            }
+            fields.encode_raw(&mut self.buf);
+            Ok(())
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.buf.len()
+    }
+
+    fn clear(&mut self) {
+        self.buf.clear();
+        self.len = 0;
+        self.final_only = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Counter, Gauge, Histogram, Summary};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_writer() {
+        let labels = HashMap::from([("label1".to_string(), "value1".to_string())]);
+        let mut counter = Counter::from("counter_name").with(labels.clone());
+        let mut gauge = Gauge::from("gauge_name").with(labels.clone());
+        let mut histogram = Histogram::from("histogram_name").with(labels.clone());
+        let mut summary = Summary::from("summary_name").with(labels);
+
+        let mut writer = PrometheusOperationsWriter::new();
+        writer.push(counter.add(1.5));
+        writer.push(gauge.set(88.8));
+        writer.push(histogram.observe(0.5));
+        writer.push(summary.observe(0.5));
+        writer.extend(vec![counter.inc(), gauge.dec()]);
+
+        let mut prom_ops: PrometheusOperations = Default::default();
+        prom_ops.push(counter.add(1.5));
+        prom_ops.push(gauge.set(88.8));
+        prom_ops.push(histogram.observe(0.5));
+        prom_ops.push(summary.observe(0.5));
+        prom_ops.push(counter.inc());
+        prom_ops.push(gauge.dec());
+
+        assert_eq!(writer.len(), 6);
+        assert_eq!(writer.to_operations().unwrap(), prom_ops);
+
+        // Round trip through prost::Message
+        let decoded =
+            PrometheusOperationsWriter::decode(writer.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded.len(), 6);
+        assert_eq!(decoded.to_operations().unwrap(), prom_ops);
+
+        let writer = writer.final_only();
+        assert!(writer.to_operations().unwrap().final_only);
+        let decoded =
+            PrometheusOperationsWriter::decode(writer.encode_to_vec().as_slice()).unwrap();
+        assert!(decoded.is_final_only());
+        assert_eq!(decoded.as_bytes(), writer.as_bytes());
+    }
+}